@@ -3,11 +3,13 @@ pub enum RoutingName {
     Root,
     Persons,
     Village,
+    Night,
 }
 
 #[derive(Clone)]
 pub enum ConsoleCommand {
     Routing {
+        #[allow(dead_code)]
         name: RoutingName,
         args: Vec<String>,
     },
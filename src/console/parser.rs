@@ -1,71 +1,3 @@
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn major_test() {
-        let command = "vg list";
-        let parts = command.split(' ').collect::<Vec<&str>>();
-
-        if let Some((_,)) = gpt!("vg", "kill"; &parts => u128) {
-            panic!()
-        } else if gpt!("vg", "list"; &parts => ).is_some() {
-        } else {
-            panic!()
-        }
-    }
-
-    #[test]
-    fn test_name() {
-        assert_eq!(map_type!(["salam"] => u32), None);
-        assert_eq!(map_type!(["10"] => u32), Some((10,)));
-        assert_eq!(map_type!(["10"] => String, u32), None);
-        assert_eq!(
-            map_type!(["count", "10"] => String, u32),
-            Some(("count".to_string(), 10))
-        );
-    }
-
-    #[test]
-    fn test_2() {
-        if let Some((arg1, arg2)) = map_type!(["count", "20"] => String, u32) {
-            assert_eq!(arg1, "count".to_string());
-            assert_eq!(arg2, 20);
-        } else {
-            panic!()
-        }
-    }
-
-    #[test]
-    fn test_3() {
-        if let Some((arg1,)) = gpt!("count"; ["count", "20"] => u32) {
-            assert_eq!(arg1, 20);
-        } else {
-            panic!()
-        }
-    }
-
-    #[test]
-    fn test_4() {
-        if let Some((vg_id,)) = gpt!("vg", "kill"; ["vg", "kill", "123456"] => u128) {
-            assert_eq!(vg_id, 123456)
-        } else {
-            panic!()
-        }
-    }
-
-    #[test]
-    fn test_5() {
-        if let Some((a1, a2, vg_id)) = gpt!(; ["vg", "kill", "123456"] => String, String, u128) {
-            assert_eq!(a1, "vg".to_string());
-            assert_eq!(a2, "kill".to_string());
-            assert_eq!(vg_id, 123456)
-        } else {
-            panic!()
-        }
-    }
-}
-
 /// Guarded map type
 #[macro_export]
 macro_rules! gpt {
@@ -153,5 +85,68 @@ macro_rules! map_type {
     };
 }
 
-pub use gpt;
-pub use map_type;
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn major_test() {
+        let command = "vg list";
+        let parts = command.split(' ').collect::<Vec<&str>>();
+
+        if let Some((_,)) = gpt!("vg", "kill"; &parts => u128) {
+            panic!()
+        } else if gpt!("vg", "list"; &parts => ).is_some() {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn test_name() {
+        assert_eq!(map_type!(["salam"] => u32), None);
+        assert_eq!(map_type!(["10"] => u32), Some((10,)));
+        assert_eq!(map_type!(["10"] => String, u32), None);
+        assert_eq!(
+            map_type!(["count", "10"] => String, u32),
+            Some(("count".to_string(), 10))
+        );
+    }
+
+    #[test]
+    fn test_2() {
+        if let Some((arg1, arg2)) = map_type!(["count", "20"] => String, u32) {
+            assert_eq!(arg1, "count".to_string());
+            assert_eq!(arg2, 20);
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn test_3() {
+        if let Some((arg1,)) = gpt!("count"; ["count", "20"] => u32) {
+            assert_eq!(arg1, 20);
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn test_4() {
+        if let Some((vg_id,)) = gpt!("vg", "kill"; ["vg", "kill", "123456"] => u128) {
+            assert_eq!(vg_id, 123456)
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn test_5() {
+        if let Some((a1, a2, vg_id)) = gpt!(; ["vg", "kill", "123456"] => String, String, u128) {
+            assert_eq!(a1, "vg".to_string());
+            assert_eq!(a2, "kill".to_string());
+            assert_eq!(vg_id, 123456)
+        } else {
+            panic!()
+        }
+    }
+}
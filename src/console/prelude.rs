@@ -1,10 +1,12 @@
 use tokio::sync::mpsc::{Receiver, Sender};
 
 use crate::{
-    gpt,
+    console_answer, gpt,
     world::{
-        world_inlet::{FromHeaven, WorldInlet},
-        WorldAntenna,
+        person::roles::Role,
+        village::periods::{AssignmentMode, Daytime, PeriodPlan},
+        world_inlet::{Caller, FromHeaven, NightChoice, WorldInlet},
+        World, WorldAntenna,
     },
 };
 
@@ -42,6 +44,8 @@ pub async fn receive_neither_console_or_other<T>(
 
 pub struct ConsoleMotor {
     dispatcher: ConsoleHandler,
+    /// Who is typing, unless the input starts with `as <caller_id>`.
+    caller: Caller,
     tx: Sender<WorldInlet>,
     antenna: WorldAntenna,
 }
@@ -50,6 +54,7 @@ impl ConsoleMotor {
     pub fn new(rx: Sender<WorldInlet>, antenna: WorldAntenna) -> Self {
         Self {
             dispatcher: get_dispatcher(),
+            caller: Caller::new(World::CONSOLE_CALLER),
             tx: rx,
             antenna,
         }
@@ -59,8 +64,21 @@ impl ConsoleMotor {
         &self,
         input: String,
     ) -> std::ops::ControlFlow<(), dptree::prelude::DependencyMap> {
+        let (caller, input) = match input
+            .strip_prefix("as ")
+            .and_then(|rest| rest.split_once(' '))
+        {
+            Some((caller_id, input)) => (Caller::new(caller_id), input.to_string()),
+            None => (self.caller.clone(), input),
+        };
+
         self.dispatcher
-            .dispatch(dptree::deps![input, self.tx.clone(), self.antenna.clone()])
+            .dispatch(dptree::deps![
+                input,
+                caller,
+                self.tx.clone(),
+                self.antenna.clone()
+            ])
             .await
     }
 }
@@ -70,14 +88,41 @@ fn get_dispatcher() -> ConsoleHandler {
         root().branch(
             routing("vg", RoutingName::Village)
                 .branch(ending("kill", parse_kill_village).endpoint(kill_village))
+                .branch(ending("kick", parse_kick).endpoint(kick_person))
+                .branch(ending("ban", parse_kick).endpoint(ban_person))
                 .branch(unmapped_ending("list").endpoint(list_villages))
+                .branch(ending("pause", parse_village_id).endpoint(pause_village))
+                .branch(ending("resume", parse_village_id).endpoint(resume_village))
+                .branch(ending("skip", parse_village_id).endpoint(skip_phase))
+                .branch(ending("start", parse_village_id).endpoint(start_now))
+                .branch(ending("status", parse_village_status).endpoint(village_status))
+                .branch(ending("lynch", parse_lynch_vote).endpoint(lynch_vote))
+                .branch(ending("reveal", parse_reveal).endpoint(reveal))
+                .branch(ending("assign", parse_assignment).endpoint(configure_assignment))
+                .branch(ending("say", parse_send_message).endpoint(send_message))
+                .branch(ending("nominate", parse_nominate).endpoint(nominate))
+                .branch(ending("guilty", parse_verdict).endpoint(guilty))
+                .branch(ending("innocent", parse_verdict).endpoint(innocent))
                 .branch(unmapped_ending("new").endpoint(new_village))
+                .branch(unmapped_ending("quick").endpoint(new_quick_village))
+                .branch(ending("plan", parse_plan).endpoint(new_planned_village))
                 .branch(
                     routing("pr", RoutingName::Persons)
                         .branch(ending("add", parse_add_person).endpoint(add_person))
                         .branch(ending("fill", parse_fill_person).endpoint(fill_person))
+                        .branch(ending("leave", parse_leave_village).endpoint(leave_village))
+                        .branch(ending("prefer", parse_prefer_role).endpoint(prefer_role))
+                        .branch(ending("rematch", parse_rematch).endpoint(answer_rematch))
                         .endpoint(|| async { println!("Unknown persons command.") }),
                 )
+                .branch(
+                    routing("nt", RoutingName::Night)
+                        .branch(ending("eat", parse_night_choice).endpoint(wolves_choice))
+                        .branch(ending("save", parse_night_choice).endpoint(doctor_choice))
+                        .branch(ending("see", parse_night_choice).endpoint(seer_choice))
+                        .branch(ending("kill", parse_night_choice).endpoint(serial_killer_choice))
+                        .endpoint(|| async { println!("Unknown night command.") }),
+                )
                 .endpoint(|| async { println!("Unknown village command") }),
         ),
     )
@@ -88,35 +133,196 @@ fn parse_kill_village(args: &[String]) -> Option<String> {
     Some(village_id)
 }
 
-async fn kill_village(village_id: String, rx: Sender<WorldInlet>) {
-    rx.send(WorldInlet::FromHeaven(FromHeaven::KillVillage {
-        village_id,
-    }))
+async fn kill_village(village_id: String, caller: Caller, rx: Sender<WorldInlet>) {
+    rx.send(WorldInlet::from_heaven(
+        caller,
+        FromHeaven::KillVillage { village_id },
+    ))
+    .await
+    .unwrap_or_default()
+}
+
+fn parse_kick(args: &[String]) -> Option<(String, String)> {
+    Some(gpt!(; args => String, String)?)
+}
+
+async fn send_kick(
+    village_id: String,
+    person_id: String,
+    ban: bool,
+    caller: Caller,
+    rx: Sender<WorldInlet>,
+) {
+    rx.send(WorldInlet::from_heaven(
+        caller,
+        FromHeaven::KickPerson {
+            village_id,
+            person_id,
+            ban,
+        },
+    ))
+    .await
+    .unwrap_or_default()
+}
+
+async fn kick_person(
+    (village_id, person_id): (String, String),
+    caller: Caller,
+    rx: Sender<WorldInlet>,
+) {
+    send_kick(village_id, person_id, false, caller, rx).await
+}
+
+async fn ban_person(
+    (village_id, person_id): (String, String),
+    caller: Caller,
+    rx: Sender<WorldInlet>,
+) {
+    send_kick(village_id, person_id, true, caller, rx).await
+}
+
+fn parse_village_id(args: &[String]) -> Option<String> {
+    let (village_id,) = gpt!(; args => String)?;
+    Some(village_id)
+}
+
+async fn pause_village(village_id: String, caller: Caller, rx: Sender<WorldInlet>) {
+    rx.send(WorldInlet::from_heaven(
+        caller,
+        FromHeaven::PauseVillage { village_id },
+    ))
+    .await
+    .unwrap_or_default()
+}
+
+async fn resume_village(village_id: String, caller: Caller, rx: Sender<WorldInlet>) {
+    rx.send(WorldInlet::from_heaven(
+        caller,
+        FromHeaven::ResumeVillage { village_id },
+    ))
+    .await
+    .unwrap_or_default()
+}
+
+async fn skip_phase(village_id: String, caller: Caller, rx: Sender<WorldInlet>) {
+    rx.send(WorldInlet::from_heaven(
+        caller,
+        FromHeaven::SkipPhase { village_id },
+    ))
+    .await
+    .unwrap_or_default()
+}
+
+async fn start_now(village_id: String, caller: Caller, rx: Sender<WorldInlet>) {
+    rx.send(WorldInlet::from_heaven(
+        caller,
+        FromHeaven::StartNow { village_id },
+    ))
     .await
     .unwrap_or_default()
 }
 
-async fn list_villages(rx: Sender<WorldInlet>) {
-    rx.send(WorldInlet::FromHeaven(FromHeaven::ListVillages))
+async fn list_villages(caller: Caller, rx: Sender<WorldInlet>) {
+    rx.send(WorldInlet::from_heaven(caller, FromHeaven::ListVillages))
         .await
         .unwrap_or_default()
 }
 
-async fn new_village(rx: Sender<WorldInlet>) {
-    rx.send(WorldInlet::FromHeaven(FromHeaven::NewVillage))
+fn parse_village_status(args: &[String]) -> Option<String> {
+    let (village_id,) = gpt!(; args => String)?;
+    Some(village_id)
+}
+
+async fn village_status(village_id: String, antenna: WorldAntenna) {
+    match antenna.ask_village_status(&village_id).await {
+        Some(status) => {
+            console_answer!(
+                "{} ( {} ) is in {:?} period.",
+                status.village_name,
+                status.village_id,
+                status.period
+            );
+            for person in status.persons {
+                match person.revealed {
+                    Some(revealed) => println!("{} ☠️ {}", person.person_id, revealed),
+                    None => println!("{} 🙂", person.person_id),
+                }
+            }
+        }
+        None => {
+            console_answer!("Village with id {village_id} not found!");
+        }
+    }
+}
+
+async fn new_village(caller: Caller, rx: Sender<WorldInlet>) {
+    rx.send(WorldInlet::from_heaven(caller, FromHeaven::NewVillage))
         .await
         .unwrap_or_default()
 }
 
+async fn new_quick_village(caller: Caller, rx: Sender<WorldInlet>) {
+    rx.send(WorldInlet::from_heaven(caller, FromHeaven::NewQuickVillage))
+        .await
+        .unwrap_or_default()
+}
+
+/// `max_cycles` ( 0 to play until someone wins ), then the daytimes in order.
+fn parse_plan(args: &[String]) -> Option<Result<PeriodPlan, String>> {
+    let (max_cycles,) = gpt!(; args => u32)?;
+    let daytimes = args
+        .get(1..)?
+        .iter()
+        .map(|daytime| match daytime.as_str() {
+            "night" => Some(Daytime::MidNight),
+            "sun" => Some(Daytime::SunRaise),
+            "lynch" => Some(Daytime::LynchTime),
+            "dusk" => Some(Daytime::Dusk),
+            _ => None,
+        })
+        .collect::<Option<_>>()?;
+
+    Some(PeriodPlan::custom(
+        daytimes,
+        Some(max_cycles).filter(|max| *max != 0),
+    ))
+}
+
+async fn new_planned_village(
+    plan: Result<PeriodPlan, String>,
+    caller: Caller,
+    rx: Sender<WorldInlet>,
+) {
+    match plan {
+        Ok(plan) => rx
+            .send(WorldInlet::from_heaven(
+                caller,
+                FromHeaven::NewPlannedVillage(plan),
+            ))
+            .await
+            .unwrap_or_default(),
+        Err(reason) => {
+            console_answer!("{reason}");
+        }
+    }
+}
+
 fn parse_add_person(args: &[String]) -> Option<(String, String)> {
     Some(gpt!(; args => String, String)?)
 }
 
-async fn add_person((village_id, person_name): (String, String), rx: Sender<WorldInlet>) {
-    rx.send(WorldInlet::FromHeaven(FromHeaven::RequestPerson {
-        village_id,
-        person_name,
-    }))
+async fn add_person(
+    (village_id, person_name): (String, String),
+    caller: Caller,
+    rx: Sender<WorldInlet>,
+) {
+    rx.send(WorldInlet::from_heaven(
+        caller,
+        FromHeaven::RequestPerson {
+            village_id,
+            person_name,
+        },
+    ))
     .await
     .unwrap_or_default()
 }
@@ -125,16 +331,289 @@ fn parse_fill_person(args: &[String]) -> Option<(String, u8)> {
     Some(gpt!(; args => String, u8)?)
 }
 
-async fn fill_person((village_id, count): (String, u8), rx: Sender<WorldInlet>) {
+async fn fill_person((village_id, count): (String, u8), caller: Caller, rx: Sender<WorldInlet>) {
     println!("Filling persons ...");
-    rx.send(WorldInlet::FromHeaven(FromHeaven::FillPersons {
-        village_id,
-        count,
-    }))
+    rx.send(WorldInlet::from_heaven(
+        caller,
+        FromHeaven::FillPersons { village_id, count },
+    ))
+    .await
+    .unwrap_or_default()
+}
+
+/// `<village_id> normal|chaos|prefs|custom [role codes ...]`
+fn parse_assignment(args: &[String]) -> Option<(String, AssignmentMode)> {
+    let (village_id, mode) = gpt!(; args => String, String)?;
+    let mode = match mode.as_str() {
+        "normal" => AssignmentMode::Normal,
+        "chaos" => AssignmentMode::Chaos,
+        "prefs" => AssignmentMode::Preferences,
+        "custom" => AssignmentMode::Custom(
+            args.get(2..)?
+                .iter()
+                .map(|code| code.parse::<u8>().map(Role::from))
+                .collect::<Result<_, _>>()
+                .ok()?,
+        ),
+        _ => return None,
+    };
+
+    Some((village_id, mode))
+}
+
+async fn configure_assignment(
+    (village_id, mode): (String, AssignmentMode),
+    caller: Caller,
+    rx: Sender<WorldInlet>,
+) {
+    rx.send(WorldInlet::from_heaven(
+        caller,
+        FromHeaven::ConfigureAssignment { village_id, mode },
+    ))
+    .await
+    .unwrap_or_default()
+}
+
+fn parse_leave_village(args: &[String]) -> Option<(String, String)> {
+    Some(gpt!(; args => String, String)?)
+}
+
+async fn leave_village(
+    (village_id, person_id): (String, String),
+    caller: Caller,
+    rx: Sender<WorldInlet>,
+) {
+    rx.send(WorldInlet::from_heaven(
+        caller,
+        FromHeaven::LeaveVillage {
+            village_id,
+            person_id,
+        },
+    ))
+    .await
+    .unwrap_or_default()
+}
+
+fn parse_prefer_role(args: &[String]) -> Option<(String, String, u8)> {
+    Some(gpt!(; args => String, String, u8)?)
+}
+
+async fn prefer_role(
+    (village_id, person_id, role_code): (String, String, u8),
+    caller: Caller,
+    rx: Sender<WorldInlet>,
+) {
+    rx.send(WorldInlet::from_heaven(
+        caller,
+        FromHeaven::PreferRole {
+            village_id,
+            person_id,
+            role: role_code.into(),
+        },
+    ))
+    .await
+    .unwrap_or_default()
+}
+
+/// `<village_id> <person_id> yes|no`
+fn parse_rematch(args: &[String]) -> Option<(String, String, bool)> {
+    let (village_id, person_id, answer) = gpt!(; args => String, String, String)?;
+    let accept = match answer.as_str() {
+        "yes" => true,
+        "no" => false,
+        _ => return None,
+    };
+
+    Some((village_id, person_id, accept))
+}
+
+async fn answer_rematch(
+    (village_id, person_id, accept): (String, String, bool),
+    caller: Caller,
+    rx: Sender<WorldInlet>,
+) {
+    rx.send(WorldInlet::from_heaven(
+        caller,
+        FromHeaven::AnswerRematch {
+            village_id,
+            person_id,
+            accept,
+        },
+    ))
+    .await
+    .unwrap_or_default()
+}
+
+fn parse_reveal(args: &[String]) -> Option<(String, String)> {
+    Some(gpt!(; args => String, String)?)
+}
+
+async fn reveal((village_id, person_id): (String, String), caller: Caller, rx: Sender<WorldInlet>) {
+    rx.send(WorldInlet::from_heaven(
+        caller,
+        FromHeaven::Reveal {
+            village_id,
+            person_id,
+        },
+    ))
+    .await
+    .unwrap_or_default()
+}
+
+fn parse_send_message(args: &[String]) -> Option<(String, String, String)> {
+    let (village_id, sender_id) = gpt!(; args => String, String)?;
+    let text = args.get(2..)?.join(" ");
+    if text.is_empty() {
+        return None;
+    }
+
+    Some((village_id, sender_id, text))
+}
+
+async fn send_message(
+    (village_id, sender_id, text): (String, String, String),
+    caller: Caller,
+    rx: Sender<WorldInlet>,
+) {
+    rx.send(WorldInlet::from_heaven(
+        caller,
+        FromHeaven::SendMessage {
+            village_id,
+            sender_id,
+            text,
+        },
+    ))
+    .await
+    .unwrap_or_default()
+}
+
+fn parse_lynch_vote(args: &[String]) -> Option<(String, String, String)> {
+    Some(gpt!(; args => String, String, String)?)
+}
+
+async fn lynch_vote(
+    (village_id, voter_id, target_id): (String, String, String),
+    caller: Caller,
+    rx: Sender<WorldInlet>,
+) {
+    rx.send(WorldInlet::from_heaven(
+        caller,
+        FromHeaven::LynchVote {
+            village_id,
+            voter_id,
+            target_id,
+        },
+    ))
+    .await
+    .unwrap_or_default()
+}
+
+fn parse_nominate(args: &[String]) -> Option<(String, String, String)> {
+    Some(gpt!(; args => String, String, String)?)
+}
+
+async fn nominate(
+    (village_id, nominator_id, nominee_id): (String, String, String),
+    caller: Caller,
+    rx: Sender<WorldInlet>,
+) {
+    rx.send(WorldInlet::from_heaven(
+        caller,
+        FromHeaven::Nominate {
+            village_id,
+            nominator_id,
+            nominee_id,
+        },
+    ))
+    .await
+    .unwrap_or_default()
+}
+
+fn parse_verdict(args: &[String]) -> Option<(String, String)> {
+    Some(gpt!(; args => String, String)?)
+}
+
+async fn send_verdict(
+    village_id: String,
+    voter_id: String,
+    guilty: bool,
+    caller: Caller,
+    rx: Sender<WorldInlet>,
+) {
+    rx.send(WorldInlet::from_heaven(
+        caller,
+        FromHeaven::Verdict {
+            village_id,
+            voter_id,
+            guilty,
+        },
+    ))
+    .await
+    .unwrap_or_default()
+}
+
+async fn guilty((village_id, voter_id): (String, String), caller: Caller, rx: Sender<WorldInlet>) {
+    send_verdict(village_id, voter_id, true, caller, rx).await
+}
+
+async fn innocent(
+    (village_id, voter_id): (String, String),
+    caller: Caller,
+    rx: Sender<WorldInlet>,
+) {
+    send_verdict(village_id, voter_id, false, caller, rx).await
+}
+
+fn parse_night_choice(args: &[String]) -> Option<(String, String)> {
+    Some(gpt!(; args => String, String)?)
+}
+
+async fn send_night_choice(
+    village_id: String,
+    choice: NightChoice,
+    caller: Caller,
+    rx: Sender<WorldInlet>,
+) {
+    rx.send(WorldInlet::from_heaven(
+        caller,
+        FromHeaven::NightChoice { village_id, choice },
+    ))
     .await
     .unwrap_or_default()
 }
 
+async fn wolves_choice(
+    (village_id, person_id): (String, String),
+    caller: Caller,
+    rx: Sender<WorldInlet>,
+) {
+    send_night_choice(village_id, NightChoice::Wolves(person_id), caller, rx).await
+}
+
+async fn doctor_choice(
+    (village_id, person_id): (String, String),
+    caller: Caller,
+    rx: Sender<WorldInlet>,
+) {
+    send_night_choice(village_id, NightChoice::Doctor(person_id), caller, rx).await
+}
+
+async fn seer_choice(
+    (village_id, person_id): (String, String),
+    caller: Caller,
+    rx: Sender<WorldInlet>,
+) {
+    send_night_choice(village_id, NightChoice::Seer(person_id), caller, rx).await
+}
+
+async fn serial_killer_choice(
+    (village_id, person_id): (String, String),
+    caller: Caller,
+    rx: Sender<WorldInlet>,
+) {
+    send_night_choice(village_id, NightChoice::SerialKiller(person_id), caller, rx).await
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -142,7 +621,9 @@ mod tests {
     #[tokio::test]
     async fn test_name() {
         let dp = get_dispatcher();
-        let _ = dp.dispatch(dptree::deps!["vg pr dick 12345678 10"]).await;
-        let _ = dp.dispatch(dptree::deps!["vg pare"]).await;
+        let _ = dp
+            .dispatch(dptree::deps!["vg pr dick 12345678 10".to_string()])
+            .await;
+        let _ = dp.dispatch(dptree::deps!["vg pare".to_string()]).await;
     }
 }
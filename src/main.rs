@@ -20,6 +20,64 @@ async fn handle_world_input(_tx: &Sender<WorldInlet>, antenna: &WorldAntenna, in
             Err(err) => println!("[🧁❌]: {err}"),
         },
         WorldOutlet::VillageList(_) => todo!(),
+        WorldOutlet::PermissionDenied {
+            caller,
+            village_id,
+            action,
+        } => {
+            println!(
+                "[🧁❌]: {} is not allowed to {action} village {village_id}, only its host or admins are.",
+                caller.id()
+            );
+        }
+        WorldOutlet::ToPerson {
+            village_id,
+            person_id,
+            data,
+        } => match data {
+            world_outlet::ToPerson::RoleChanged(role) => {
+                println!("[🧀 {village_id} ➡️ {person_id}]: You are now {role}.");
+            }
+            world_outlet::ToPerson::ChatMessage(message) => {
+                println!(
+                    "[💬 {village_id} ➡️ {person_id}] ({:?}) {}: {}",
+                    message.channel, message.sender_id, message.text
+                );
+            }
+            world_outlet::ToPerson::Spectate(event) => match event {
+                world_inlet::SpectatorEvent::Roles(persons) => {
+                    println!("[👻 {village_id} ➡️ {person_id}]: Roles of this village:");
+                    for person in persons {
+                        println!("{} ({})", person.get_id(), person.get_role());
+                    }
+                }
+                world_inlet::SpectatorEvent::NightChoice(choice) => {
+                    println!("[👻 {village_id} ➡️ {person_id}]: {:?}", choice);
+                }
+            },
+            world_outlet::ToPerson::AfkWarning(missed) => {
+                println!(
+                    "[🧀 {village_id} ➡️ {person_id}]: You missed {missed} actions, the forest is watching 🌲."
+                );
+            }
+            world_outlet::ToPerson::RolePreference(result) => match result {
+                Ok(role) => println!("[🧀 {village_id} ➡️ {person_id}]: You'd like to be {role}."),
+                Err(reason) => {
+                    println!("[🧀 {village_id} ➡️ {person_id}]: Role request rejected: {reason}")
+                }
+            },
+            world_outlet::ToPerson::Kicked { banned } => match banned {
+                true => println!(
+                    "[🧀 {village_id} ➡️ {person_id}]: You're banned from this village 🚫."
+                ),
+                false => println!(
+                    "[🧀 {village_id} ➡️ {person_id}]: You're kicked from this village 👢."
+                ),
+            },
+            world_outlet::ToPerson::MessageRejected(reason) => {
+                println!("[🧀 {village_id} ➡️ {person_id}]: Message rejected: {reason}");
+            }
+        },
         WorldOutlet::WithVillage { village_id, data } => match data {
             RawString(raw) => println!("[🧀 {village_id}]: {raw}"),
             VillageDisposed => println!("[🧀 {village_id}]: Disposed!"),
@@ -31,21 +89,92 @@ async fn handle_world_input(_tx: &Sender<WorldInlet>, antenna: &WorldAntenna, in
                 )
             }
             NewPeriod(period) => println!("[🧀 {village_id}]: New period {:?}", period),
+            AssignmentConfigured { mode, balance } => {
+                println!("[🧀 {village_id}]: Roles will be assigned as {:?}.", mode);
+                println!(
+                    "[! ⚖️] Village {}, Wolves {}, Neutral {}.",
+                    balance.village, balance.wolves, balance.neutral
+                );
+                if balance.impossible {
+                    println!("[! ⚖️] This setup can't be played!");
+                }
+                for warning in balance.warnings {
+                    println!("[! ⚠️] {warning}");
+                }
+                for suggestion in balance.suggestions {
+                    println!("[! 💡] {suggestion}");
+                }
+            }
+            AssignmentRejected(reason) => {
+                println!("[🧀 {village_id}]: Assignment rejected: {reason}");
+            }
             PopulationTimedOut => println!("[🧀 {village_id}] Failed to populate, disposing ..."),
+            Paused { remaining } => {
+                println!("[🧀 {village_id}]: Paused ⏸️ with {:#?} left.", remaining);
+            }
+            Resumed { remaining } => {
+                println!("[🧀 {village_id}]: Resumed ▶️ with {:#?} left.", remaining);
+            }
+            PauseRejected(reason) => {
+                println!("[🧀 {village_id}]: Pause rejected: {reason}");
+            }
+            PhaseSkipped => println!("[🧀 {village_id}]: Phase skipped ⏭️."),
+            SkipRejected(reason) => {
+                println!("[🧀 {village_id}]: Skip rejected: {reason}");
+            }
+            StartRejected(reason) => {
+                println!("[🧀 {village_id}]: Can't start now: {reason}");
+            }
+            TimeReminder { remaining } => {
+                println!("[🧀 {village_id}]: ⏳ {:#?} left!", remaining);
+            }
             DaytimeCycled(daytime, dur) => {
                 println!(
                     "[🧀 {village_id}]: New daytime {} for {:#?} long",
                     daytime, dur
                 )
             }
+            LeaveResult(result) => match result {
+                world_inlet::LeaveResult::Left {
+                    person_id,
+                    current_count,
+                } => {
+                    println!(
+                        "[🧀 {village_id}]: {} left ({} persons in village).",
+                        person_id, current_count
+                    );
+                }
+                world_inlet::LeaveResult::Forfeited(person_id) => {
+                    println!("[🧀 {village_id}]: {} left the game.", person_id);
+                }
+                world_inlet::LeaveResult::Failed(err) => {
+                    println!("[🧀 {village_id}]: Failed leaving: {}.", err);
+                }
+            },
+            PersonKicked {
+                person_id,
+                banned,
+                current_count,
+            } => {
+                let how = match banned {
+                    true => "banned",
+                    false => "kicked",
+                };
+                println!(
+                    "[🧀 {village_id}]: {} is {how} ({} persons in village).",
+                    person_id, current_count
+                );
+            }
+            KickRejected(reason) => println!("[🧀 {village_id}]: Kick rejected: {reason}"),
             AddPersonResult(result) => match result {
                 world_inlet::AddPersonResult::Added {
                     person_id,
                     current_count,
+                    time_left,
                 } => {
                     println!(
-                        "[🧀 {village_id}]: Created person with id {} ({} persons in village).",
-                        person_id, current_count
+                        "[🧀 {village_id}]: Created person with id {} ({} persons in village, {:#?} left to join).",
+                        person_id, current_count, time_left
                     );
                 }
                 world_inlet::AddPersonResult::Failed(err) => {
@@ -62,12 +191,36 @@ async fn handle_world_input(_tx: &Sender<WorldInlet>, antenna: &WorldAntenna, in
                         person_id
                     );
                 }
+                world_inlet::NightActionResult::PersonKilled(person_id) => {
+                    println!(
+                        "[🧀 {village_id}]: A person is killed by the serial killer last night ({}).",
+                        person_id
+                    );
+                }
                 world_inlet::NightActionResult::PersonSaved(person_id) => {
                     println!(
                         "[🧀 {village_id}]: A person is saved last night ({}).",
                         person_id
                     );
                 }
+                world_inlet::NightActionResult::PersonGuarded {
+                    saved,
+                    bodyguard: Some(bodyguard),
+                } => {
+                    println!(
+                        "[🧀 {village_id}]: Bodyguard ({}) died protecting a person last night ({}).",
+                        bodyguard, saved
+                    );
+                }
+                world_inlet::NightActionResult::PersonGuarded {
+                    saved,
+                    bodyguard: None,
+                } => {
+                    println!(
+                        "[🧀 {village_id}]: Someone died protecting a person last night ({}).",
+                        saved
+                    );
+                }
                 world_inlet::NightActionResult::SeerReport(person_id, is_wolf) => {
                     let is_wolf_text = match is_wolf {
                         true => "",
@@ -79,6 +232,124 @@ async fn handle_world_input(_tx: &Sender<WorldInlet>, antenna: &WorldAntenna, in
                     );
                 }
             },
+            PersonRevealed { person_id, role } => {
+                println!("[🧀 {village_id}]: {person_id} revealed to be the {role}!");
+            }
+            RevealRejected(reason) => {
+                println!("[🧀 {village_id}]: Reveal rejected: {reason}");
+            }
+            ChatMessage(message) => {
+                println!("[💬 {village_id}] {}: {}", message.sender_id, message.text);
+            }
+            LynchTurn { available_persons } => {
+                println!("[🧀 {village_id}]: Time to lynch someone, who is guilty?");
+                println!("[! ⚔️] Possible lynchable persons:");
+                for person in available_persons {
+                    println!("{}", person.get_id());
+                }
+            }
+            LynchVoted { voter, target } => {
+                println!("[🧀 {village_id}]: {voter} voted to lynch {target}.");
+            }
+            LynchVoteRejected(reason) => {
+                println!("[🧀 {village_id}]: Lynch vote rejected: {reason}");
+            }
+            Nominated { nominator, nominee } => {
+                println!("[🧀 {village_id}]: {nominator} nominated {nominee} for a trial.");
+            }
+            OnTrial { accused, dur } => {
+                println!(
+                    "[🧀 {village_id}]: {} is on trial, {:#?} to defend.",
+                    accused, dur
+                );
+            }
+            LastWords { person_id, dur } => {
+                println!(
+                    "[🧀 {village_id}]: {} is dying, {:#?} for last words.",
+                    person_id, dur
+                );
+            }
+            VerdictTime { accused, dur } => {
+                println!(
+                    "[🧀 {village_id}]: Is {} guilty or innocent? {:#?} to vote.",
+                    accused, dur
+                );
+            }
+            VerdictVoted { voter, guilty } => {
+                let verdict = match guilty {
+                    true => "guilty",
+                    false => "innocent",
+                };
+                println!("[🧀 {village_id}]: {voter} voted {verdict}.");
+            }
+            TrialActionRejected(reason) => {
+                println!("[🧀 {village_id}]: Trial action rejected: {reason}");
+            }
+            LynchResultReport(report) => match report {
+                world_inlet::LynchResult::NoneLynched => {
+                    println!("[🧀 {village_id}]: No one lynched today.");
+                }
+                world_inlet::LynchResult::PersonLynched(person_id) => {
+                    println!("[🧀 {village_id}]: A person is lynched ({}).", person_id);
+                }
+                world_inlet::LynchResult::Acquitted(person_id) => {
+                    println!("[🧀 {village_id}]: {} is found innocent.", person_id);
+                }
+            },
+            GameEnded(report) => {
+                match report.winner {
+                    Some(team) => println!("[🧀 {village_id}]: Game ended, {} won!", team),
+                    None => println!("[🧀 {village_id}]: Game ended."),
+                }
+                for neutral in report.neutral_winners {
+                    println!("[! 🎭] Neutral winner: {}", neutral);
+                }
+                for person in report.persons {
+                    let state = match person.is_alive() {
+                        true => "alive",
+                        false => "dead",
+                    };
+                    println!("{} ({}, {})", person.get_id(), person.get_role(), state);
+                }
+            }
+            RematchOffered { dur } => {
+                println!(
+                    "[🧀 {village_id}]: Play again? Accept within {:#?} 🔁.",
+                    dur
+                );
+            }
+            RematchAnswered { person_id, accept } => match accept {
+                true => println!("[🧀 {village_id}]: {person_id} plays again."),
+                false => println!("[🧀 {village_id}]: {person_id} is out."),
+            },
+            RematchRejected(reason) => {
+                println!("[🧀 {village_id}]: Rematch rejected: {reason}");
+            }
+            RematchStarted { persons } => {
+                println!(
+                    "[🧀 {village_id}]: A new round begins with {} persons!",
+                    persons.len()
+                );
+                for person_id in persons {
+                    println!("{}", person_id);
+                }
+            }
+            RematchCancelled(reason) => {
+                println!("[🧀 {village_id}]: No rematch: {reason}");
+            }
+            PersonDied {
+                person_id,
+                cause,
+                revealed,
+            } => {
+                println!(
+                    "[🧀 {village_id}]: {} died ({:?}), they were {}.",
+                    person_id, cause, revealed
+                );
+            }
+            NightChoiceRejected(reason) => {
+                println!("[🧀 {village_id}]: Night choice rejected: {reason}");
+            }
             NightTurn {
                 turn,
                 available_persons,
@@ -101,9 +372,29 @@ async fn handle_world_input(_tx: &Sender<WorldInlet>, antenna: &WorldAntenna, in
                             village_id, village_name,
                         );
                         println!("[! ❤️‍🩹] Possible saveable persons:");
+                        for person in available_persons {
+                            println!("{}", person.get_id());
+                        }
+                    }
+                    world_outlet::NightTurn::Bodyguard => {
+                        println!(
+                            "[🧀 {}]: Bodyguard in {} village, who to guard tonight?",
+                            village_id, village_name,
+                        );
+                        println!("[! 🛡️] Possible guardable persons:");
+                        for person in available_persons {
+                            println!("{}", person.get_id());
+                        }
+                    }
+                    world_outlet::NightTurn::SerialKiller => {
+                        println!(
+                            "[🧀 {}]: Serial killer in {} village, who to kill tonight?",
+                            village_id, village_name,
+                        );
+                        println!("[! 🔪] Possible killable persons:");
                         for person in available_persons {
                             match person.get_role() {
-                                person::roles::Role::Doctor => continue,
+                                person::roles::Role::SerialKiller => continue,
                                 _ => println!("{}", person.get_id()),
                             }
                         }
@@ -143,7 +434,7 @@ async fn main() {
     loop {
         match receive_neither_console_or_other(&mut console_receiver, &mut world_receiver).await {
             Received::FromConsole(from_console) => {
-                console_motor.dispatch(from_console).await;
+                let _ = console_motor.dispatch(from_console).await;
             }
             Received::FromOther(from_world) => handle_world_input(&tx, &antenna, from_world).await,
         }
@@ -5,7 +5,10 @@ use mongodb::{
 
 use crate::{
     model,
-    world::person::{roles::Role, Person},
+    world::{
+        person::{roles::Role, Person},
+        village::periods::AssignmentMode,
+    },
 };
 
 model! {
@@ -14,7 +17,8 @@ model! {
         village_id: String,
         is_alive: bool,
         role_code: u8,
-        eatable: bool
+        eatable: bool,
+        preferred_role: u8
     }
 }
 
@@ -29,7 +33,7 @@ pub async fn add_person_to_village(
 
     match collection
         .insert_one(
-            PersonDoc::new(name.to_string(), village_id.to_string(), true, 0, false),
+            PersonDoc::new(name.to_string(), village_id.to_string(), true, 0, false, 0),
             None,
         )
         .await
@@ -104,6 +108,60 @@ pub async fn get_all_alive_persons(client: &Client, village_id: &str) -> Vec<Per
     persons
 }
 
+/// All persons of the village, dead or alive.
+pub async fn get_village_persons(client: &Client, village_id: &str) -> Vec<Person> {
+    let db = client.database("rustling");
+    // Get a handle to a collection in the database.
+    let collection = db.collection::<PersonDoc>("persons");
+
+    let mut persons = vec![];
+    if let Ok(mut found) = collection.find(doc! {"village_id": village_id}, None).await {
+        while found.advance().await.unwrap() {
+            let curr = found.current();
+            persons.push(
+                Person::new(
+                    curr.get_object_id("_id").unwrap().to_string(),
+                    curr.get_str("village_id").unwrap(),
+                    curr.get_i32("role_code").unwrap().try_into().unwrap(),
+                    curr.get_bool("eatable").unwrap(),
+                )
+                .with_alive(curr.get_bool("is_alive").unwrap()),
+            );
+        }
+    };
+    persons
+}
+
+pub async fn get_alive_persons_with_role(
+    client: &Client,
+    village_id: &str,
+    role: Role,
+) -> Vec<Person> {
+    let db = client.database("rustling");
+    // Get a handle to a collection in the database.
+    let collection = db.collection::<PersonDoc>("persons");
+
+    let mut persons = vec![];
+    if let Ok(mut found) = collection
+        .find(
+            doc! {"village_id": village_id, "is_alive": true, "role_code": role as i32},
+            None,
+        )
+        .await
+    {
+        while found.advance().await.unwrap() {
+            let curr = found.current();
+            persons.push(Person::new(
+                curr.get_object_id("_id").unwrap().to_string(),
+                curr.get_str("village_id").unwrap(),
+                curr.get_i32("role_code").unwrap().try_into().unwrap(),
+                curr.get_bool("eatable").unwrap(),
+            ));
+        }
+    };
+    persons
+}
+
 pub async fn cleanup_persons(
     client: &Client,
     village_id: &str,
@@ -117,6 +175,44 @@ pub async fn cleanup_persons(
         .await
 }
 
+/// Brings every person of the village back to life, without a role, for a new round.
+pub async fn reset_persons(
+    client: &Client,
+    village_id: &str,
+) -> Result<mongodb::results::UpdateResult, mongodb::error::Error> {
+    let db = client.database("rustling");
+    // Get a handle to a collection in the database.
+    let collection = db.collection::<PersonDoc>("persons");
+
+    collection
+        .update_many(
+            doc! {"village_id": village_id},
+            doc! {"$set": {"is_alive": true, "role_code": Role::NoRole as i32, "eatable": false}},
+            None,
+        )
+        .await
+}
+
+/// Removes a person from the village, returns `false` if he isn't there.
+pub async fn remove_person(
+    client: &Client,
+    village_id: &str,
+    person_id: &str,
+) -> Result<bool, mongodb::error::Error> {
+    let db = client.database("rustling");
+    // Get a handle to a collection in the database.
+    let collection = db.collection::<PersonDoc>("persons");
+
+    let person_id = match ObjectId::parse_str(person_id) {
+        Ok(person_id) => person_id,
+        Err(_) => return Ok(false),
+    };
+    let deleted = collection
+        .delete_one(doc! {"_id": person_id, "village_id": village_id}, None)
+        .await?;
+    Ok(deleted.deleted_count > 0)
+}
+
 pub async fn person_name_exists(client: &Client, village_id: &str, person_name: &str) -> bool {
     let db = client.database("rustling");
     // Get a handle to a collection in the database.
@@ -132,9 +228,31 @@ pub async fn person_name_exists(client: &Client, village_id: &str, person_name:
         .is_some()
 }
 
+/// Sets the role a person would like to get, [`Role::NoRole`] for no preference.
+pub async fn set_preferred_role(
+    client: &Client,
+    person_id: &str,
+    role: Role,
+) -> Result<(), mongodb::error::Error> {
+    let db = client.database("rustling");
+    // Get a handle to a collection in the database.
+    let collection = db.collection::<PersonDoc>("persons");
+
+    let _ = collection
+        .update_one(
+            doc! {"_id": ObjectId::parse_str(person_id).unwrap() },
+            doc! {"$set": {"preferred_role": role as i32}},
+            None,
+        )
+        .await?;
+    Ok(())
+}
+
 pub async fn assign_roles(
     client: &Client,
     village_id: &str,
+    protector: Role,
+    mode: &AssignmentMode,
 ) -> Result<Vec<Role>, mongodb::error::Error> {
     let db = client.database("rustling");
     // Get a handle to a collection in the database.
@@ -144,26 +262,28 @@ pub async fn assign_roles(
         .find(doc! {"village_id": village_id}, None)
         .await?;
 
-    // count the number of villagers in the village
-    let villagers = count_village_persons(client, village_id).await;
-    let roles = crate::world::person::assigner::roles(villagers);
-
-    // Iterate over the results of the cursor.
-    let mut counter = 0;
+    // Collect persons and their preferences first.
+    let mut ids = vec![];
+    let mut preferences = vec![];
     while persons.advance().await? {
         let cur = persons.current();
-        let id = cur.get_object_id("_id").unwrap();
+        ids.push(cur.get_object_id("_id").unwrap());
+        preferences.push(Role::from(
+            u8::try_from(cur.get_i32("preferred_role").unwrap_or(0)).unwrap_or(0),
+        ));
+    }
+
+    let roles = crate::world::person::assigner::assign(mode, protector, &preferences);
 
-        let role_code = roles[counter];
+    for (id, role_code) in ids.into_iter().zip(roles.iter()) {
         let is_eatable = role_code.is_eatable();
         let _ = collection
             .update_one(
                 doc! {"_id": id},
-                doc! {"$set": {"role_code": role_code as u32, "eatable": is_eatable}},
+                doc! {"$set": {"role_code": *role_code as i32, "eatable": is_eatable}},
                 None,
             )
             .await?;
-        counter += 1;
     }
 
     Ok(roles)
@@ -184,6 +304,40 @@ pub async fn mark_dead(client: &Client, person_id: &str) -> Result<(), mongodb::
     Ok(())
 }
 
+/// Changes role of an alive person in the middle of the game.
+pub async fn change_person_role(
+    client: &Client,
+    person_id: &str,
+    role: Role,
+) -> Result<(), mongodb::error::Error> {
+    let db = client.database("rustling");
+    // Get a handle to a collection in the database.
+    let collection = db.collection::<PersonDoc>("persons");
+
+    let _ = collection
+        .update_one(
+            doc! {"_id": ObjectId::parse_str(person_id).unwrap() },
+            doc! {"$set": {"role_code": role as i32, "eatable": role.is_eatable()}},
+            None,
+        )
+        .await?;
+    Ok(())
+}
+
+/// Name of a person, `None` if he isn't in the village.
+pub async fn get_person_name(client: &Client, village_id: &str, person_id: &str) -> Option<String> {
+    let db = client.database("rustling");
+    // Get a handle to a collection in the database.
+    let collection = db.collection::<PersonDoc>("persons");
+
+    let person_id = ObjectId::parse_str(person_id).ok()?;
+    collection
+        .find_one(doc! {"_id": person_id, "village_id": village_id}, None)
+        .await
+        .unwrap()
+        .map(|found| found.name)
+}
+
 pub async fn get_person_role(client: &Client, person_id: &str) -> Role {
     let db = client.database("rustling");
     // Get a handle to a collection in the database.
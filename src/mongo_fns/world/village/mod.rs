@@ -10,6 +10,13 @@ model! {
     }
 }
 
+model! {
+    pub struct VillageBan {
+        village_id: String,
+        name: String
+    }
+}
+
 pub(crate) async fn get_village_period(client: &Client, village_id: &str) -> Option<RawPeriod> {
     let db = client.database("rustling");
     // Get a handle to a collection in the database.
@@ -74,3 +81,47 @@ pub(crate) async fn cleanup_village_period(
         .delete_one(doc! {"village_id": village_id}, None)
         .await
 }
+
+/// Bans a person name from joining the village again.
+pub(crate) async fn ban_person_name(
+    client: &Client,
+    village_id: &str,
+    name: &str,
+) -> Result<(), mongodb::error::Error> {
+    let db = client.database("rustling");
+    // Get a handle to a collection in the database.
+    let collection = db.collection::<VillageBan>("village_bans");
+
+    collection
+        .insert_one(
+            VillageBan::new(village_id.to_string(), name.to_string()),
+            None,
+        )
+        .await?;
+    Ok(())
+}
+
+pub(crate) async fn is_person_name_banned(client: &Client, village_id: &str, name: &str) -> bool {
+    let db = client.database("rustling");
+    // Get a handle to a collection in the database.
+    let collection = db.collection::<VillageBan>("village_bans");
+
+    collection
+        .find_one(doc! {"village_id": village_id, "name": name}, None)
+        .await
+        .unwrap()
+        .is_some()
+}
+
+pub(crate) async fn cleanup_village_bans(
+    client: &Client,
+    village_id: &str,
+) -> Result<mongodb::results::DeleteResult, mongodb::error::Error> {
+    let db = client.database("rustling");
+    // Get a handle to a collection in the database.
+    let collection = db.collection::<VillageBan>("village_bans");
+
+    collection
+        .delete_many(doc! {"village_id": village_id}, None)
+        .await
+}
@@ -23,10 +23,7 @@ where
     pub async fn ask(&self, asked: TAsked) -> Option<TAnswered> {
         let (req, rx) = Request::<TAsked, TAnswered>::new(asked);
         match self.sender.send(req).await {
-            Ok(_) => match rx.await {
-                Ok(data) => Some(data),
-                Err(_) => None,
-            },
+            Ok(_) => rx.await.ok(),
             Err(_) => None,
         }
     }
@@ -68,7 +65,7 @@ where
         Args: Send + 'static,
     {
         let (sender, receiver) = mpsc::channel(self.receiver_buffer);
-        let _ = tokio::spawn(async move { on_data_received(receiver, args).await });
+        tokio::spawn(async move { on_data_received(receiver, args).await });
 
         Antenna { sender }
     }
@@ -93,7 +90,7 @@ mod tests {
         let tower_template = Tower::<String, String>::template(1024);
 
         let antenna =
-            tower_template.create_antenna("Test".to_string(), |mut rx, args| async move {
+            tower_template.create_antenna("Hello World".to_string(), |mut rx, args| async move {
                 loop {
                     let received = rx.recv().await;
                     match received {
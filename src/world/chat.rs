@@ -0,0 +1,17 @@
+/// Where a chat message is posted, and who can read it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChatChannel {
+    /// Every one in the village, during the day.
+    Village,
+    /// Alive wolves only, at night.
+    Wolves,
+    /// Dead persons only, any time.
+    Graveyard,
+}
+
+#[derive(Debug, Clone)]
+pub struct ChatMessage {
+    pub channel: ChatChannel,
+    pub sender_id: String,
+    pub text: String,
+}
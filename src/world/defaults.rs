@@ -2,7 +2,13 @@ use std::time::Duration;
 
 use crate::world::village::periods::RawPeriod;
 
-use super::village::periods::{AssignmentMode, Daytime, Period};
+use super::village::{
+    config::{
+        AfkRules, DeathReveal, GraveyardRules, PopulationExtension, ProtectionRules, RematchRules,
+        TrialRules, VillageConfig,
+    },
+    periods::{AssignmentMode, Daytime, Period, PeriodPlan},
+};
 
 pub(crate) fn default_period_maker(raw: &RawPeriod) -> Period {
     match raw {
@@ -12,13 +18,54 @@ pub(crate) fn default_period_maker(raw: &RawPeriod) -> Period {
             max_dur: Duration::from_secs(30),
         },
         RawPeriod::Assignments => Period::Assignments(AssignmentMode::Normal),
-        RawPeriod::DaytimeCycle => Period::DaytimeCycle(|dt| match dt {
-            Daytime::MidNight => Duration::from_secs(30),
-            Daytime::SunRaise => Duration::from_secs(30),
-            Daytime::LynchTime => Duration::from_secs(30),
-        }),
+        RawPeriod::DaytimeCycle => Period::DaytimeCycle {
+            daytime_dur: |dt| match dt {
+                Daytime::MidNight => Duration::from_secs(30),
+                Daytime::SunRaise => Duration::from_secs(30),
+                Daytime::LynchTime => Duration::from_secs(30),
+                Daytime::Dusk => Duration::from_secs(15),
+            },
+            last_words_dur: Duration::from_secs(10),
+        },
         RawPeriod::Ending => Period::Ending,
         RawPeriod::None => Period::None,
         RawPeriod::FirstNight => Period::FirstNight(Duration::from_secs(20)),
     }
 }
+
+pub(crate) fn default_village_config() -> VillageConfig {
+    VillageConfig {
+        protection: ProtectionRules {
+            allow_repeat_target: false,
+            max_self_protects: 1,
+            bodyguard: false,
+        },
+        death_reveal: DeathReveal::Role,
+        graveyard: GraveyardRules {
+            enabled: true,
+            spectate: false,
+        },
+        trial: Some(TrialRules {
+            defense_dur: Duration::from_secs(20),
+            verdict_dur: Duration::from_secs(20),
+        }),
+        afk: Some(AfkRules { max_missed: 3 }),
+        population_extension: Some(PopulationExtension {
+            per_join: Duration::from_secs(10),
+            max_total: Duration::from_secs(60),
+        }),
+        rematch: Some(RematchRules {
+            answer_dur: Duration::from_secs(30),
+        }),
+        reminders: vec![Duration::from_secs(30), Duration::from_secs(10)],
+        plan: PeriodPlan::classic(),
+    }
+}
+
+/// One night and one lynch, then the game is over.
+pub(crate) fn quick_village_config() -> VillageConfig {
+    VillageConfig {
+        plan: PeriodPlan::quick(),
+        ..default_village_config()
+    }
+}
@@ -1,3 +1,4 @@
+pub mod chat;
 mod defaults;
 pub mod person;
 pub mod village;
@@ -7,7 +8,12 @@ pub mod world_outlet;
 
 pub use world_antenna::{AskWorld, WorldAnswered, WorldAntenna};
 
-use std::{collections::HashMap, error::Error, thread, time::Duration};
+use std::{
+    collections::{HashMap, HashSet},
+    error::Error,
+    thread,
+    time::Duration,
+};
 
 use mongodb::{options::ClientOptions, Client};
 use rand::prelude::*;
@@ -19,20 +25,22 @@ use tokio::sync::{
 use crate::{
     mongo_fns::world::person::{
         count_village_persons, get_all_alive_persons, get_eatable_alive_persons,
+        get_village_persons,
     },
     tower::{Request, Tower},
-    world::world_outlet::NightTurn,
+    world::{person::roles::Role, world_outlet::NightTurn},
 };
 
 use self::{
     village::{
+        config::VillageConfig,
         periods::{Period, RawPeriod},
         simplified_village::SimplifiedVillage,
         Village,
     },
     world_antenna::ToWorldAntenna,
-    world_inlet::{AddPersonResult, FromHeaven, FromVillage, WorldInlet},
-    world_outlet::{SendWorldOutletContext, WorldOutlet},
+    world_inlet::{AddPersonResult, Caller, FromHeaven, FromVillage, WorldInlet},
+    world_outlet::{PersonStatus, SendWorldOutletContext, WorldOutlet},
 };
 
 #[derive(Debug)]
@@ -44,6 +52,8 @@ enum ReceivedKind {
 pub struct World {
     client: Client,
     villages: HashMap<String, SimplifiedVillage>,
+    /// Allowed to manage every village.
+    admins: HashSet<Caller>,
     receiver: Receiver<WorldInlet>,
     to_heaven_tx: Sender<WorldOutlet>,
     antenna: WorldAntenna,
@@ -65,6 +75,9 @@ impl World {
         "Fenghuang",
     ];
 
+    /// Caller of commands typed in the console, an admin.
+    pub const CONSOLE_CALLER: &'static str = "console";
+
     pub async fn new() -> (World, Receiver<WorldOutlet>) {
         // Parse a connection string into an options struct.
         let mut client_options = ClientOptions::parse("mongodb://localhost:27017")
@@ -85,6 +98,7 @@ impl World {
             World {
                 client,
                 villages: HashMap::new(),
+                admins: HashSet::from([Caller::new(Self::CONSOLE_CALLER)]),
                 receiver,
                 to_heaven_tx,
                 antenna: antenna.to_world_antenna(),
@@ -98,7 +112,9 @@ impl World {
     fn create_village(
         &mut self,
         village_name: Option<&str>,
+        host: Caller,
         period_maker: fn(&RawPeriod) -> Period,
+        config: VillageConfig,
     ) -> &SimplifiedVillage {
         let mut rng = thread_rng();
         let sv = Village::new(
@@ -107,7 +123,9 @@ impl World {
                 Some(name) => name,
                 None => Self::VILLAGE_NAME_SAMPLES.choose(&mut rng).unwrap(),
             },
+            host,
             period_maker,
+            config,
             self.to_world_sender.clone(),
         )
         .simplify();
@@ -120,9 +138,15 @@ impl World {
     fn create_village_default_receiver(
         &mut self,
         village_name: Option<&str>,
+        host: Caller,
         period_maker: fn(&RawPeriod) -> Period,
     ) -> &SimplifiedVillage {
-        self.create_village(village_name, period_maker)
+        self.create_village(
+            village_name,
+            host,
+            period_maker,
+            defaults::default_village_config(),
+        )
     }
 
     fn kill_village(&mut self, village_id: &str) {
@@ -145,7 +169,7 @@ impl World {
         }
     }
 
-    fn send_out(&self) -> SendWorldOutletContext {
+    fn send_out(&self) -> SendWorldOutletContext<'_> {
         WorldOutlet::send_ctx(&self.to_heaven_tx)
     }
 
@@ -155,6 +179,29 @@ impl World {
             .await
     }
 
+    /// Returns the village if `caller` is its host or an admin, otherwise notifies.
+    async fn get_hosted_village_or_notify(
+        &self,
+        caller: &Caller,
+        village_id: &str,
+        action: &str,
+    ) -> Option<&SimplifiedVillage> {
+        let village = self.get_village_or_notify(village_id).await?;
+        if village.get_host() == caller || self.admins.contains(caller) {
+            return Some(village);
+        }
+
+        self.send_out()
+            .send(WorldOutlet::PermissionDenied {
+                caller: caller.clone(),
+                village_id: village_id.to_string(),
+                action: action.to_string(),
+            })
+            .await
+            .unwrap_or_default();
+        None
+    }
+
     pub fn antenna(&self) -> &WorldAntenna {
         &self.antenna
     }
@@ -163,7 +210,11 @@ impl World {
         &self.to_world_sender
     }
 
-    async fn handle_from_heaven(&mut self, from_heaven: FromHeaven) -> Result<(), Box<dyn Error>> {
+    async fn handle_from_heaven(
+        &mut self,
+        caller: Caller,
+        from_heaven: FromHeaven,
+    ) -> Result<(), Box<dyn Error>> {
         use FromHeaven::*;
 
         match from_heaven {
@@ -179,9 +230,6 @@ impl World {
                             max_dur: _,
                         } => {
                             village.add_player(&person_name).await?;
-                            village
-                                .extend_population_dur(Duration::from_secs(10))
-                                .await?;
                             Ok(())
                         }
                         _ => Ok(()),
@@ -190,6 +238,16 @@ impl World {
                     Ok(())
                 }
             }
+            LeaveVillage {
+                village_id,
+                person_id,
+            } => {
+                if let Some(village) = self.get_village_or_notify(&village_id).await {
+                    village.leave(&person_id).await?;
+                }
+
+                Ok(())
+            }
             FillPersons { village_id, count } => {
                 if let Some(village) = self.get_village_or_notify(&village_id).await {
                     for i in 0..count {
@@ -209,12 +267,166 @@ impl World {
                 Ok(())
             }
             KillVillage { village_id } => {
-                if let Some(village) = self.get_village_or_notify(&village_id).await {
+                if let Some(village) = self
+                    .get_hosted_village_or_notify(&caller, &village_id, "kill")
+                    .await
+                {
                     village.die().await?;
                 }
 
                 Ok(())
             }
+            KickPerson {
+                village_id,
+                person_id,
+                ban,
+            } => {
+                let action = match ban {
+                    true => "ban persons of",
+                    false => "kick persons of",
+                };
+                if let Some(village) = self
+                    .get_hosted_village_or_notify(&caller, &village_id, action)
+                    .await
+                {
+                    village.kick(&person_id, ban).await?;
+                }
+
+                Ok(())
+            }
+            PauseVillage { village_id } => {
+                if let Some(village) = self
+                    .get_hosted_village_or_notify(&caller, &village_id, "pause")
+                    .await
+                {
+                    village.pause().await?;
+                }
+
+                Ok(())
+            }
+            ResumeVillage { village_id } => {
+                if let Some(village) = self
+                    .get_hosted_village_or_notify(&caller, &village_id, "resume")
+                    .await
+                {
+                    village.resume().await?;
+                }
+
+                Ok(())
+            }
+            SkipPhase { village_id } => {
+                if let Some(village) = self
+                    .get_hosted_village_or_notify(&caller, &village_id, "skip")
+                    .await
+                {
+                    village.skip_phase().await?;
+                }
+
+                Ok(())
+            }
+            StartNow { village_id } => {
+                if let Some(village) = self
+                    .get_hosted_village_or_notify(&caller, &village_id, "start")
+                    .await
+                {
+                    village.start_now().await?;
+                }
+
+                Ok(())
+            }
+            NightChoice { village_id, choice } => {
+                if let Some(village) = self.get_village_or_notify(&village_id).await {
+                    village.night_choice(choice).await?;
+                }
+
+                Ok(())
+            }
+            Reveal {
+                village_id,
+                person_id,
+            } => {
+                if let Some(village) = self.get_village_or_notify(&village_id).await {
+                    village.reveal(&person_id).await?;
+                }
+
+                Ok(())
+            }
+            SendMessage {
+                village_id,
+                sender_id,
+                text,
+            } => {
+                if let Some(village) = self.get_village_or_notify(&village_id).await {
+                    village.send_message(&sender_id, &text).await?;
+                }
+
+                Ok(())
+            }
+            LynchVote {
+                village_id,
+                voter_id,
+                target_id,
+            } => {
+                if let Some(village) = self.get_village_or_notify(&village_id).await {
+                    village.lynch_vote(&voter_id, &target_id).await?;
+                }
+
+                Ok(())
+            }
+            ConfigureAssignment { village_id, mode } => {
+                if let Some(village) = self
+                    .get_hosted_village_or_notify(&caller, &village_id, "configure")
+                    .await
+                {
+                    village.configure_assignment(mode).await?;
+                }
+
+                Ok(())
+            }
+            PreferRole {
+                village_id,
+                person_id,
+                role,
+            } => {
+                if let Some(village) = self.get_village_or_notify(&village_id).await {
+                    village.prefer_role(&person_id, role).await?;
+                }
+
+                Ok(())
+            }
+            Nominate {
+                village_id,
+                nominator_id,
+                nominee_id,
+            } => {
+                if let Some(village) = self.get_village_or_notify(&village_id).await {
+                    village.nominate(&nominator_id, &nominee_id).await?;
+                }
+
+                Ok(())
+            }
+            Verdict {
+                village_id,
+                voter_id,
+                guilty,
+            } => {
+                if let Some(village) = self.get_village_or_notify(&village_id).await {
+                    village.verdict(&voter_id, guilty).await?;
+                }
+
+                Ok(())
+            }
+            AnswerRematch {
+                village_id,
+                person_id,
+                accept,
+            } => {
+                if let Some(village) = self.get_village_or_notify(&village_id).await {
+                    village.answer_rematch(&person_id, accept).await?;
+                }
+
+                Ok(())
+            }
             ListVillages => {
                 let mut villages = vec![];
                 for village in self.villages.values() {
@@ -226,7 +438,33 @@ impl World {
                 Ok(())
             }
             NewVillage => {
-                self.create_village_default_receiver(None, defaults::default_period_maker);
+                self.create_village_default_receiver(None, caller, defaults::default_period_maker);
+                Ok(())
+            }
+            NewQuickVillage => {
+                self.create_village(
+                    None,
+                    caller,
+                    defaults::default_period_maker,
+                    defaults::quick_village_config(),
+                );
+                Ok(())
+            }
+            NewPlannedVillage(plan) => {
+                if let Err(reason) = plan.validate() {
+                    self.send_raw_string(&reason).await?;
+                    return Ok(());
+                }
+
+                self.create_village(
+                    None,
+                    caller,
+                    defaults::default_period_maker,
+                    VillageConfig {
+                        plan,
+                        ..defaults::default_village_config()
+                    },
+                );
                 Ok(())
             }
             Nothing => Ok(()),
@@ -261,26 +499,21 @@ impl World {
                     .period_ready(period)
                     .await?;
 
-                match period {
-                    RawPeriod::Assignments => {
-                        let joined_persons = count_village_persons(&self.client, &village_id).await;
-                        self.send_out()
-                            .with_village(&village_id)
-                            .populated(joined_persons)
-                            .await?;
-                    }
-                    RawPeriod::None => todo!(),
-                    RawPeriod::Populating => todo!(),
-                    RawPeriod::FirstNight => todo!(),
-                    RawPeriod::DaytimeCycle => todo!(),
-                    RawPeriod::Ending => todo!(),
-                };
+                // Only the end of populating has more to report, custom plans may
+                // announce any other period.
+                if let RawPeriod::Assignments = period {
+                    let joined_persons = count_village_persons(&self.client, &village_id).await;
+                    self.send_out()
+                        .with_village(&village_id)
+                        .populated(joined_persons)
+                        .await?;
+                }
 
                 Ok(())
             }
             NewPeriod(period) => {
                 let village = self.get_mut_village(&village_id);
-                village.village.set_current_period(period);
+                village.village.set_current_period(period.clone());
 
                 self.send_out()
                     .with_village(&village_id)
@@ -298,6 +531,78 @@ impl World {
 
                 Ok(())
             }
+            AssignmentConfigured { mode, balance } => {
+                self.send_out()
+                    .with_village(&village_id)
+                    .send(world_outlet::WithVillage::AssignmentConfigured { mode, balance })
+                    .await?;
+
+                Ok(())
+            }
+            AssignmentRejected(reason) => {
+                self.send_out()
+                    .with_village(&village_id)
+                    .send(world_outlet::WithVillage::AssignmentRejected(reason))
+                    .await?;
+
+                Ok(())
+            }
+            Paused { remaining } => {
+                self.send_out()
+                    .with_village(&village_id)
+                    .send(world_outlet::WithVillage::Paused { remaining })
+                    .await?;
+
+                Ok(())
+            }
+            Resumed { remaining } => {
+                self.send_out()
+                    .with_village(&village_id)
+                    .send(world_outlet::WithVillage::Resumed { remaining })
+                    .await?;
+
+                Ok(())
+            }
+            PauseRejected(reason) => {
+                self.send_out()
+                    .with_village(&village_id)
+                    .send(world_outlet::WithVillage::PauseRejected(reason))
+                    .await?;
+
+                Ok(())
+            }
+            PhaseSkipped => {
+                self.send_out()
+                    .with_village(&village_id)
+                    .send(world_outlet::WithVillage::PhaseSkipped)
+                    .await?;
+
+                Ok(())
+            }
+            SkipRejected(reason) => {
+                self.send_out()
+                    .with_village(&village_id)
+                    .send(world_outlet::WithVillage::SkipRejected(reason))
+                    .await?;
+
+                Ok(())
+            }
+            StartRejected(reason) => {
+                self.send_out()
+                    .with_village(&village_id)
+                    .send(world_outlet::WithVillage::StartRejected(reason))
+                    .await?;
+
+                Ok(())
+            }
+            TimeReminder { remaining } => {
+                self.send_out()
+                    .with_village(&village_id)
+                    .send(world_outlet::WithVillage::TimeReminder { remaining })
+                    .await?;
+
+                Ok(())
+            }
             DaytimeCycled(daytime, dur) => {
                 self.send_out()
                     .with_village(&village_id)
@@ -306,6 +611,14 @@ impl World {
 
                 Ok(())
             }
+            LeaveVillage(result) => {
+                self.send_out()
+                    .with_village(&village_id)
+                    .send(world_outlet::WithVillage::LeaveResult(result))
+                    .await?;
+
+                Ok(())
+            }
             AddPerson(result) => {
                 self.send_out()
                     .with_village(&village_id)
@@ -326,18 +639,48 @@ impl World {
 
                 Ok(())
             }
-            DoctorTurn => {
+            DoctorTurn {
+                protector,
+                excluded,
+            } => {
+                let saveable_persons = get_all_alive_persons(&self.client, &village_id)
+                    .await
+                    .into_iter()
+                    .filter(|p| !excluded.contains(&p.get_id()))
+                    .collect();
+                self.send_out()
+                    .with_village(&village_id)
+                    .send(world_outlet::WithVillage::NightTurn {
+                        turn: match protector {
+                            Role::Bodyguard => NightTurn::Bodyguard,
+                            _ => NightTurn::Doctor,
+                        },
+                        available_persons: saveable_persons,
+                    })
+                    .await?;
+
+                Ok(())
+            }
+            SerialKillerTurn => {
                 let all_persons = get_all_alive_persons(&self.client, &village_id).await;
                 self.send_out()
                     .with_village(&village_id)
                     .send(world_outlet::WithVillage::NightTurn {
-                        turn: NightTurn::Doctor,
+                        turn: NightTurn::SerialKiller,
                         available_persons: all_persons,
                     })
                     .await?;
 
                 Ok(())
             }
+            NightChoiceRejected(reason) => {
+                self.send_out()
+                    .with_village(&village_id)
+                    .send(world_outlet::WithVillage::NightChoiceRejected(reason))
+                    .await?;
+
+                Ok(())
+            }
             SeerTurn => {
                 let all_persons = get_all_alive_persons(&self.client, &village_id).await;
                 self.send_out()
@@ -358,6 +701,271 @@ impl World {
 
                 Ok(())
             }
+            PersonDied {
+                person_id,
+                cause,
+                revealed,
+            } => {
+                self.send_out()
+                    .with_village(&village_id)
+                    .send(world_outlet::WithVillage::PersonDied {
+                        person_id,
+                        cause,
+                        revealed,
+                    })
+                    .await?;
+
+                Ok(())
+            }
+            PersonConverted { person_id, role } => {
+                self.send_out()
+                    .with_village(&village_id)
+                    .to_person(&person_id, world_outlet::ToPerson::RoleChanged(role))
+                    .await?;
+
+                Ok(())
+            }
+            PersonRevealed { person_id, role } => {
+                self.send_out()
+                    .with_village(&village_id)
+                    .send(world_outlet::WithVillage::PersonRevealed { person_id, role })
+                    .await?;
+
+                Ok(())
+            }
+            RevealRejected(reason) => {
+                self.send_out()
+                    .with_village(&village_id)
+                    .send(world_outlet::WithVillage::RevealRejected(reason))
+                    .await?;
+
+                Ok(())
+            }
+            ChatMessage(message) => {
+                self.send_out()
+                    .with_village(&village_id)
+                    .send(world_outlet::WithVillage::ChatMessage(message))
+                    .await?;
+
+                Ok(())
+            }
+            PrivateChatMessage {
+                recipients,
+                message,
+            } => {
+                for recipient in recipients {
+                    self.send_out()
+                        .with_village(&village_id)
+                        .to_person(
+                            &recipient,
+                            world_outlet::ToPerson::ChatMessage(message.clone()),
+                        )
+                        .await?;
+                }
+
+                Ok(())
+            }
+            Spectate { recipients, event } => {
+                for recipient in recipients {
+                    self.send_out()
+                        .with_village(&village_id)
+                        .to_person(&recipient, world_outlet::ToPerson::Spectate(event.clone()))
+                        .await?;
+                }
+
+                Ok(())
+            }
+            PersonKicked {
+                person_id,
+                banned,
+                current_count,
+            } => {
+                self.send_out()
+                    .with_village(&village_id)
+                    .to_person(&person_id, world_outlet::ToPerson::Kicked { banned })
+                    .await?;
+                self.send_out()
+                    .with_village(&village_id)
+                    .send(world_outlet::WithVillage::PersonKicked {
+                        person_id,
+                        banned,
+                        current_count,
+                    })
+                    .await?;
+
+                Ok(())
+            }
+            KickRejected(reason) => {
+                self.send_out()
+                    .with_village(&village_id)
+                    .send(world_outlet::WithVillage::KickRejected(reason))
+                    .await?;
+
+                Ok(())
+            }
+            AfkWarning { person_id, missed } => {
+                self.send_out()
+                    .with_village(&village_id)
+                    .to_person(&person_id, world_outlet::ToPerson::AfkWarning(missed))
+                    .await?;
+
+                Ok(())
+            }
+            RolePreferred { person_id, result } => {
+                self.send_out()
+                    .with_village(&village_id)
+                    .to_person(&person_id, world_outlet::ToPerson::RolePreference(result))
+                    .await?;
+
+                Ok(())
+            }
+            MessageRejected { sender_id, reason } => {
+                self.send_out()
+                    .with_village(&village_id)
+                    .to_person(&sender_id, world_outlet::ToPerson::MessageRejected(reason))
+                    .await?;
+
+                Ok(())
+            }
+            LynchTurn => {
+                let all_persons = get_all_alive_persons(&self.client, &village_id).await;
+                self.send_out()
+                    .with_village(&village_id)
+                    .send(world_outlet::WithVillage::LynchTurn {
+                        available_persons: all_persons,
+                    })
+                    .await?;
+
+                Ok(())
+            }
+            LynchVoted { voter, target } => {
+                self.send_out()
+                    .with_village(&village_id)
+                    .send(world_outlet::WithVillage::LynchVoted { voter, target })
+                    .await?;
+
+                Ok(())
+            }
+            LynchVoteRejected(reason) => {
+                self.send_out()
+                    .with_village(&village_id)
+                    .send(world_outlet::WithVillage::LynchVoteRejected(reason))
+                    .await?;
+
+                Ok(())
+            }
+            Nominated { nominator, nominee } => {
+                self.send_out()
+                    .with_village(&village_id)
+                    .send(world_outlet::WithVillage::Nominated { nominator, nominee })
+                    .await?;
+
+                Ok(())
+            }
+            OnTrial { accused, dur } => {
+                self.send_out()
+                    .with_village(&village_id)
+                    .send(world_outlet::WithVillage::OnTrial { accused, dur })
+                    .await?;
+
+                Ok(())
+            }
+            LastWords { person_id, dur } => {
+                self.send_out()
+                    .with_village(&village_id)
+                    .send(world_outlet::WithVillage::LastWords { person_id, dur })
+                    .await?;
+
+                Ok(())
+            }
+            VerdictTime { accused, dur } => {
+                self.send_out()
+                    .with_village(&village_id)
+                    .send(world_outlet::WithVillage::VerdictTime { accused, dur })
+                    .await?;
+
+                Ok(())
+            }
+            VerdictVoted { voter, guilty } => {
+                self.send_out()
+                    .with_village(&village_id)
+                    .send(world_outlet::WithVillage::VerdictVoted { voter, guilty })
+                    .await?;
+
+                Ok(())
+            }
+            TrialActionRejected(reason) => {
+                self.send_out()
+                    .with_village(&village_id)
+                    .send(world_outlet::WithVillage::TrialActionRejected(reason))
+                    .await?;
+
+                Ok(())
+            }
+            ReportLynchResult(report) => {
+                self.send_out()
+                    .with_village(&village_id)
+                    .send(world_outlet::WithVillage::LynchResultReport(report))
+                    .await?;
+
+                Ok(())
+            }
+            GameEnded(report) => {
+                // Villages offering a rematch are disposed later, if no one plays again.
+                let rematch = self
+                    .villages
+                    .get(&village_id)
+                    .is_some_and(|village| village.village.get_config().rematch.is_some());
+                if !rematch {
+                    self.kill_village(&village_id);
+                }
+                self.send_out()
+                    .with_village(&village_id)
+                    .send(world_outlet::WithVillage::GameEnded(report))
+                    .await?;
+
+                Ok(())
+            }
+            RematchOffered { dur } => {
+                self.send_out()
+                    .with_village(&village_id)
+                    .send(world_outlet::WithVillage::RematchOffered { dur })
+                    .await?;
+
+                Ok(())
+            }
+            RematchAnswered { person_id, accept } => {
+                self.send_out()
+                    .with_village(&village_id)
+                    .send(world_outlet::WithVillage::RematchAnswered { person_id, accept })
+                    .await?;
+
+                Ok(())
+            }
+            RematchRejected(reason) => {
+                self.send_out()
+                    .with_village(&village_id)
+                    .send(world_outlet::WithVillage::RematchRejected(reason))
+                    .await?;
+
+                Ok(())
+            }
+            RematchStarted { persons } => {
+                self.send_out()
+                    .with_village(&village_id)
+                    .send(world_outlet::WithVillage::RematchStarted { persons })
+                    .await?;
+
+                Ok(())
+            }
+            RematchCancelled(reason) => {
+                self.send_out()
+                    .with_village(&village_id)
+                    .send(world_outlet::WithVillage::RematchCancelled(reason))
+                    .await?;
+
+                Ok(())
+            }
         }
     }
 
@@ -382,6 +990,37 @@ impl World {
                 })
                 .unwrap_or_default();
 
+                Ok(())
+            }
+            AskWorld::AskVillageStatus(village_id) => {
+                let status = match self.villages.get(&village_id) {
+                    Some(village) => {
+                        let death_reveal = village.village.get_config().death_reveal;
+                        let persons = get_village_persons(&self.client, &village_id)
+                            .await
+                            .into_iter()
+                            .map(|p| PersonStatus {
+                                person_id: p.get_id(),
+                                is_alive: p.is_alive(),
+                                revealed: match p.is_alive() {
+                                    true => None,
+                                    false => Some(death_reveal.reveal(p.get_role())),
+                                },
+                            })
+                            .collect();
+
+                        Some(world_outlet::VillageStatus {
+                            village_id: village_id.clone(),
+                            village_name: village.get_village_name().to_string(),
+                            period: village.village.get_current_period().into(),
+                            persons,
+                        })
+                    }
+                    None => None,
+                };
+
+                answer(VillageStatus(status)).unwrap_or_default();
+
                 Ok(())
             }
         }
@@ -396,25 +1035,24 @@ impl World {
                 };
 
                 match received {
-                    ReceivedKind::WorldInlet(inlet) => match inlet {
-                        Some(inlet) => match inlet {
-                            WorldInlet::FromHeaven(data) => {
-                                if (self.handle_from_heaven(data).await).is_ok() {}
-                            }
-                            WorldInlet::FromVillage { village_id, data } => {
-                                self.handle_from_village(village_id, data).await.unwrap()
+                    ReceivedKind::WorldInlet(inlet) => {
+                        if let Some(inlet) = inlet {
+                            match inlet {
+                                WorldInlet::FromHeaven { caller, data } => {
+                                    if (self.handle_from_heaven(caller, data).await).is_ok() {}
+                                }
+                                WorldInlet::FromVillage { village_id, data } => {
+                                    self.handle_from_village(village_id, data).await.unwrap()
+                                }
                             }
-                        },
-
-                        None => (),
-                    },
-                    ReceivedKind::AskedWorld(request) => match request {
-                        Some(request) => {
+                        }
+                    }
+                    ReceivedKind::AskedWorld(request) => {
+                        if let Some(request) = request {
                             let (asked, sender) = request.extract();
                             self.answer_whats_asked(asked, sender).await.unwrap();
                         }
-                        None => (),
-                    },
+                    }
                 }
             }
         });
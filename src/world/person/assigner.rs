@@ -1,8 +1,24 @@
 use rand::prelude::*;
 
-use super::roles::Role;
+use crate::world::village::periods::AssignmentMode;
 
-pub fn roles(len: u64) -> Vec<Role> {
+use super::roles::{Role, Team};
+
+/// Roles for persons, in order, as the assignment `mode` says.
+///
+/// `preferences` has the requested role of each person, [`Role::NoRole`] if none.
+pub fn assign(mode: &AssignmentMode, protector: Role, preferences: &[Role]) -> Vec<Role> {
+    let len = preferences.len() as u64;
+    match mode {
+        AssignmentMode::Normal => roles(len, protector),
+        AssignmentMode::Chaos => chaos_roles(len, protector),
+        AssignmentMode::Custom(list) => custom_roles(list, len),
+        AssignmentMode::Preferences => preferred_roles(protector, preferences),
+    }
+}
+
+/// Roles for a village of `len` persons, `protector` takes the place of the doctor.
+pub fn roles(len: u64, protector: Role) -> Vec<Role> {
     let mut arr = Vec::<Role>::new();
     for _ in 0..len {
         arr.push(Role::Villager);
@@ -16,25 +32,25 @@ pub fn roles(len: u64) -> Vec<Role> {
         6 => {
             arr[0] = Role::Wolf;
             arr[1] = Role::Seer;
-            arr[2] = Role::Doctor;
+            arr[2] = protector;
         }
         7 => {
             arr[0] = Role::Wolf;
             arr[1] = Role::Seer;
-            arr[2] = Role::Doctor;
+            arr[2] = protector;
             arr[3] = Role::MasterWolf;
         }
         8 => {
             arr[0] = Role::Wolf;
             arr[1] = Role::Seer;
-            arr[2] = Role::Doctor;
+            arr[2] = protector;
             arr[3] = Role::MasterWolf;
             arr[4] = Role::Villager;
         }
         9 => {
             arr[0] = Role::Wolf;
             arr[1] = Role::Seer;
-            arr[2] = Role::Doctor;
+            arr[2] = protector;
             arr[3] = Role::MasterWolf;
             arr[4] = Role::Villager;
             arr[5] = Role::Villager;
@@ -42,7 +58,7 @@ pub fn roles(len: u64) -> Vec<Role> {
         10 => {
             arr[0] = Role::Wolf;
             arr[1] = Role::Seer;
-            arr[2] = Role::Doctor;
+            arr[2] = protector;
             arr[3] = Role::MasterWolf;
             arr[4] = Role::Villager;
             arr[5] = Role::Villager;
@@ -55,3 +71,127 @@ pub fn roles(len: u64) -> Vec<Role> {
     arr.shuffle(&mut rng);
     arr
 }
+
+/// Random roles, a wolf for every four persons and at most one neutral role.
+///
+/// Special village roles are used once at most, villagers fill the rest.
+pub fn chaos_roles(len: u64, protector: Role) -> Vec<Role> {
+    let mut rng = thread_rng();
+    let len = len as usize;
+    let mut arr = Vec::<Role>::with_capacity(len);
+
+    for _ in 0..(len / 4).max(1) {
+        arr.push(*[Role::Wolf, Role::MasterWolf].choose(&mut rng).unwrap());
+    }
+
+    if len > 6 && rng.gen_bool(0.5) {
+        arr.push(*[Role::Tanner, Role::SerialKiller].choose(&mut rng).unwrap());
+    }
+
+    let mut specials = vec![Role::Seer, protector, Role::Cursed, Role::Mayor];
+    specials.shuffle(&mut rng);
+    while arr.len() < len {
+        match specials.pop() {
+            Some(special) if rng.gen_bool(0.5) => arr.push(special),
+            _ => arr.push(Role::Villager),
+        }
+    }
+
+    arr.shuffle(&mut rng);
+    arr
+}
+
+/// Roles from a list given by the host, missing ones are villagers.
+///
+/// Extra roles are dropped at random, wolves are kept as long as possible.
+pub fn custom_roles(list: &[Role], len: u64) -> Vec<Role> {
+    let mut rng = thread_rng();
+    let mut arr = list.to_vec();
+    arr.shuffle(&mut rng);
+
+    let mut arr = fit_roles(&arr, len as usize);
+    arr.shuffle(&mut rng);
+    arr
+}
+
+/// A role list cut or filled up to `len` roles, missing ones are villagers.
+///
+/// The last roles are dropped first, but wolves are kept as long as possible.
+pub fn fit_roles(list: &[Role], len: usize) -> Vec<Role> {
+    let mut arr = list.to_vec();
+    // Stable, the others keep their order.
+    arr.sort_by_key(|role| role.team() != Team::Wolves);
+
+    arr.truncate(len);
+    arr.resize(len, Role::Villager);
+    arr
+}
+
+/// Normal roles, but each person gets his preferred role if it's still available.
+pub fn preferred_roles(protector: Role, preferences: &[Role]) -> Vec<Role> {
+    let mut pool = roles(preferences.len() as u64, protector);
+    let mut arr = vec![Role::NoRole; preferences.len()];
+
+    // Who asked first doesn't matter.
+    let mut order: Vec<usize> = (0..preferences.len()).collect();
+    order.shuffle(&mut thread_rng());
+    for i in order {
+        if let Some(found) = pool.iter().position(|role| *role == preferences[i]) {
+            arr[i] = pool.swap_remove(found);
+        }
+    }
+
+    for role in arr.iter_mut().filter(|role| **role == Role::NoRole) {
+        *role = pool.pop().unwrap();
+    }
+    arr
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn preferences_are_honoured() {
+        let preferences = [
+            Role::Seer,
+            Role::NoRole,
+            Role::Seer,
+            Role::Wolf,
+            Role::NoRole,
+        ];
+        let arr = preferred_roles(Role::Doctor, &preferences);
+        assert_eq!(arr[3], Role::Wolf);
+        assert!(arr[0] == Role::Seer || arr[2] == Role::Seer);
+
+        let mut arr = arr;
+        let mut normal = roles(5, Role::Doctor);
+        arr.sort_by_key(|role| u8::from(*role));
+        normal.sort_by_key(|role| u8::from(*role));
+        assert_eq!(arr, normal);
+    }
+
+    #[test]
+    fn custom_keeps_wolves() {
+        let mut list = vec![Role::Villager; 6];
+        list.push(Role::Wolf);
+        for _ in 0..10 {
+            let arr = custom_roles(&list, 5);
+            assert_eq!(arr.len(), 5);
+            assert!(arr.contains(&Role::Wolf));
+        }
+        assert_eq!(custom_roles(&[Role::Wolf], 3).len(), 3);
+    }
+
+    #[test]
+    fn chaos_keeps_balance() {
+        for len in 5..=10 {
+            let arr = chaos_roles(len, Role::Doctor);
+            let wolves = arr.iter().filter(|r| r.team() == Team::Wolves).count();
+            let neutrals = arr.iter().filter(|r| r.team() == Team::Neutral).count();
+            assert_eq!(arr.len() as u64, len);
+            assert_eq!(wolves, (len as usize / 4).max(1));
+            assert!(neutrals <= 1);
+        }
+    }
+}
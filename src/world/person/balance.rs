@@ -0,0 +1,149 @@
+use super::{
+    assigner::fit_roles,
+    roles::{Role, Team},
+};
+
+/// How strong each team is with a role list, and what's wrong with it.
+#[derive(Debug, Clone)]
+pub struct BalanceReport {
+    pub village: i32,
+    pub wolves: i32,
+    pub neutral: i32,
+    /// The game can't be played ( or ends right away ) with this list.
+    pub impossible: bool,
+    pub warnings: Vec<String>,
+    pub suggestions: Vec<String>,
+}
+
+/// How much a role helps its team.
+pub fn role_score(role: Role) -> i32 {
+    match role {
+        Role::NoRole => 0,
+        Role::Villager => 1,
+        Role::Wolf => 6,
+        Role::MasterWolf => 8,
+        Role::Seer => 7,
+        Role::Doctor => 5,
+        Role::Bodyguard => 4,
+        Role::Tanner => 2,
+        // May join the wolves.
+        Role::Cursed => -1,
+        Role::SerialKiller => 7,
+        Role::Mayor => 3,
+    }
+}
+
+/// Scores each team of a role list played by `persons` persons, warning about broken setups.
+pub fn evaluate(roles: &[Role], persons: usize) -> BalanceReport {
+    let mut report = BalanceReport {
+        village: 0,
+        wolves: 0,
+        neutral: 0,
+        impossible: false,
+        warnings: vec![],
+        suggestions: vec![],
+    };
+
+    if roles.len() != persons {
+        report.warnings.push(format!(
+            "There are {} roles for {} persons, extra roles are dropped ( wolves last ) and missing ones are villagers.",
+            roles.len(),
+            persons
+        ));
+        report.suggestions.push(match roles.len() < persons {
+            true => format!("Add {} more roles.", persons - roles.len()),
+            false => format!("Remove {} roles.", roles.len() - persons),
+        });
+    }
+
+    // Score the roles as they'll be played, only the dropped non-wolves may differ.
+    let played = fit_roles(roles, persons);
+
+    if played.contains(&Role::NoRole) {
+        report.impossible = true;
+        report
+            .warnings
+            .push("NoRole is not a playable role.".to_string());
+        report
+            .suggestions
+            .push("Replace NoRole with a real role.".to_string());
+    }
+
+    let mut wolves = 0;
+    for role in played.iter() {
+        let score = role_score(*role);
+        match role.team() {
+            Team::Village => report.village += score,
+            Team::Wolves => {
+                wolves += 1;
+                report.wolves += score;
+            }
+            Team::Neutral => report.neutral += score,
+        }
+    }
+    let others = played.len() - wolves;
+
+    if wolves == 0 {
+        report.impossible = true;
+        report
+            .warnings
+            .push("There are no wolves, the village wins right away.".to_string());
+        report.suggestions.push("Add a Wolf.".to_string());
+    } else if wolves >= others {
+        report.impossible = true;
+        report
+            .warnings
+            .push("Wolves are at least as many as the others, they win right away.".to_string());
+        report
+            .suggestions
+            .push("Replace some wolves with villagers.".to_string());
+    } else if report.village < report.wolves {
+        report
+            .warnings
+            .push("Wolves are much stronger than the village.".to_string());
+        report
+            .suggestions
+            .push("Add a Seer or a protector.".to_string());
+    } else if report.village > report.wolves * 3 {
+        report
+            .warnings
+            .push("The village is much stronger than wolves.".to_string());
+        report
+            .suggestions
+            .push("Add a Wolf or replace one with a MasterWolf.".to_string());
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn broken_setups() {
+        let report = evaluate(&[Role::Seer, Role::Villager, Role::Villager], 3);
+        assert!(report.impossible);
+        assert_eq!(report.wolves, 0);
+
+        let report = evaluate(&[Role::Wolf, Role::Wolf, Role::Villager], 3);
+        assert!(report.impossible);
+
+        // One role is missing, it'd be a villager.
+        let report = evaluate(&[Role::Wolf, Role::Seer, Role::Doctor, Role::Villager], 5);
+        assert!(!report.impossible);
+        assert_eq!(report.village, 14);
+        assert_eq!(report.warnings.len(), 1);
+    }
+
+    #[test]
+    fn surplus_keeps_wolves() {
+        let mut roles = vec![Role::Villager; 6];
+        roles.push(Role::Wolf);
+
+        let report = evaluate(&roles, 5);
+        assert!(!report.impossible);
+        assert_eq!(report.wolves, role_score(Role::Wolf));
+        assert_eq!(report.village, 4);
+    }
+}
@@ -1,9 +1,10 @@
 use self::roles::Role;
 
 pub mod assigner;
+pub mod balance;
 pub mod roles;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 #[allow(dead_code)]
 pub struct Person {
     person_id: String,
@@ -28,7 +29,16 @@ impl Person {
         self.person_id.to_string()
     }
 
+    pub fn with_alive(mut self, is_alive: bool) -> Self {
+        self.is_alive = is_alive;
+        self
+    }
+
     pub fn get_role(&self) -> Role {
         Role::from(self.role_code)
     }
+
+    pub fn is_alive(&self) -> bool {
+        self.is_alive
+    }
 }
@@ -1,5 +1,41 @@
 use std::fmt::Display;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Team {
+    Village,
+    Wolves,
+    /// Roles with their own win condition, they win alone.
+    Neutral,
+}
+
+impl Display for Team {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Team::Village => write!(f, "Village 🏘️"),
+            Team::Wolves => write!(f, "Wolves 🐺"),
+            Team::Neutral => write!(f, "Neutral 🎭"),
+        }
+    }
+}
+
+/// What others know about a dead person.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoleReveal {
+    Role(Role),
+    Team(Team),
+    Hidden,
+}
+
+impl Display for RoleReveal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RoleReveal::Role(role) => write!(f, "{}", role),
+            RoleReveal::Team(team) => write!(f, "{}", team),
+            RoleReveal::Hidden => write!(f, "Unknown ❔"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Role {
     NoRole,
@@ -8,11 +44,40 @@ pub enum Role {
     MasterWolf,
     Seer,
     Doctor,
+    Bodyguard,
+    Tanner,
+    /// Becomes a wolf if attacked by wolves.
+    Cursed,
+    /// Kills alone, wins if he is the last one standing.
+    SerialKiller,
+    /// His lynch vote counts twice once revealed.
+    Mayor,
 }
 
 impl Role {
     pub fn is_eatable(&self) -> bool {
-        !matches!(self, Role::Wolf | Role::MasterWolf)
+        !matches!(self, Role::Wolf | Role::MasterWolf | Role::SerialKiller)
+    }
+
+    /// Roles that may publicly reveal themselves during the day.
+    pub fn can_reveal(&self) -> bool {
+        matches!(self, Role::Mayor)
+    }
+
+    /// How much a lynch vote of this role counts, once revealed.
+    pub fn lynch_vote_weight(&self) -> u32 {
+        match self {
+            Role::Mayor => 2,
+            _ => 1,
+        }
+    }
+
+    pub fn team(&self) -> Team {
+        match self {
+            Role::Wolf | Role::MasterWolf => Team::Wolves,
+            Role::Tanner | Role::SerialKiller => Team::Neutral,
+            _ => Team::Village,
+        }
     }
 }
 
@@ -25,6 +90,11 @@ impl Display for Role {
             Role::MasterWolf => write!(f, "MasterWolf ⚡"),
             Role::Seer => write!(f, "Seer 🔍"),
             Role::Doctor => write!(f, "Doctor 🩺"),
+            Role::Bodyguard => write!(f, "Bodyguard 🛡️"),
+            Role::Tanner => write!(f, "Tanner 🪢"),
+            Role::Cursed => write!(f, "Cursed 😾"),
+            Role::SerialKiller => write!(f, "SerialKiller 🔪"),
+            Role::Mayor => write!(f, "Mayor 🎩"),
         }
     }
 }
@@ -38,6 +108,11 @@ impl From<Role> for u8 {
             Role::MasterWolf => 3,
             Role::Seer => 4,
             Role::Doctor => 5,
+            Role::Bodyguard => 6,
+            Role::Tanner => 7,
+            Role::Cursed => 8,
+            Role::SerialKiller => 9,
+            Role::Mayor => 10,
         }
     }
 }
@@ -51,6 +126,11 @@ impl From<u8> for Role {
             3 => Role::MasterWolf,
             4 => Role::Seer,
             5 => Role::Doctor,
+            6 => Role::Bodyguard,
+            7 => Role::Tanner,
+            8 => Role::Cursed,
+            9 => Role::SerialKiller,
+            10 => Role::Mayor,
             _ => Role::NoRole,
         }
     }
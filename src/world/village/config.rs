@@ -0,0 +1,134 @@
+use std::time::Duration;
+
+use crate::world::person::roles::{Role, RoleReveal};
+
+use super::periods::PeriodPlan;
+
+/// Rules applied to the protector's ( doctor or bodyguard ) night choice.
+#[derive(Debug, Clone, Copy)]
+pub struct ProtectionRules {
+    /// Whether the same person can be protected on two nights in a row.
+    pub allow_repeat_target: bool,
+    /// How many times the protector can protect itself during a game.
+    pub max_self_protects: u8,
+    /// Use a bodyguard instead of the doctor.
+    ///
+    /// The bodyguard dies in place of the person he protected, so he never protects himself.
+    pub bodyguard: bool,
+}
+
+/// Durations of a trial, replacing the plain lynch vote.
+///
+/// A suspect nominated and seconded during the day gets a defense,
+/// then the village votes guilty or innocent.
+#[derive(Debug, Clone, Copy)]
+pub struct TrialRules {
+    pub defense_dur: Duration,
+    pub verdict_dur: Duration,
+}
+
+/// Each join keeps the village populating a bit longer.
+#[derive(Debug, Clone, Copy)]
+pub struct PopulationExtension {
+    /// Added to the populating time after each join.
+    pub per_join: Duration,
+    /// Extensions stop once they add up to this.
+    pub max_total: Duration,
+}
+
+impl PopulationExtension {
+    /// Extension for the next join, with `extended` already added so far.
+    pub fn next(&self, extended: Duration) -> Duration {
+        self.per_join.min(self.max_total.saturating_sub(extended))
+    }
+}
+
+/// Idle persons are removed, so they can't stall the game.
+#[derive(Debug, Clone, Copy)]
+pub struct AfkRules {
+    /// Missed night turns or votes in a row, before being killed by the forest.
+    ///
+    /// A warning is sent one missed action before, so it's at least [`AfkRules::MIN_MISSED`].
+    pub max_missed: u8,
+}
+
+impl AfkRules {
+    /// One missed action for the warning, one more for the forest.
+    pub const MIN_MISSED: u8 = 2;
+}
+
+/// A new round in the same village, once the game is over.
+#[derive(Debug, Clone, Copy)]
+pub struct RematchRules {
+    /// How long persons have to accept, those who don't are dropped.
+    pub answer_dur: Duration,
+}
+
+/// What dead persons can do, nothing of it is visible to alive ones.
+#[derive(Debug, Clone, Copy)]
+pub struct GraveyardRules {
+    /// Dead persons can talk to each other.
+    pub enabled: bool,
+    /// Dead persons see all roles and night actions live.
+    pub spectate: bool,
+}
+
+/// What is revealed about a person when he dies.
+#[derive(Debug, Clone, Copy)]
+pub enum DeathReveal {
+    Role,
+    TeamOnly,
+    Nothing,
+}
+
+impl DeathReveal {
+    pub fn reveal(&self, role: Role) -> RoleReveal {
+        match self {
+            DeathReveal::Role => RoleReveal::Role(role),
+            DeathReveal::TeamOnly => RoleReveal::Team(role.team()),
+            DeathReveal::Nothing => RoleReveal::Hidden,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct VillageConfig {
+    pub protection: ProtectionRules,
+    pub death_reveal: DeathReveal,
+    pub graveyard: GraveyardRules,
+    /// `None` to use the plain lynch vote.
+    pub trial: Option<TrialRules>,
+    /// `None` to let idle persons be.
+    pub afk: Option<AfkRules>,
+    /// `None` to keep the populating time as is.
+    pub population_extension: Option<PopulationExtension>,
+    /// `None` to close the village once the game is over.
+    pub rematch: Option<RematchRules>,
+    /// Time left in a phase when persons are reminded of it, empty for no reminders.
+    pub reminders: Vec<Duration>,
+    pub plan: PeriodPlan,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extensions_stop_at_max_total() {
+        let rules = PopulationExtension {
+            per_join: Duration::from_secs(30),
+            max_total: Duration::from_secs(70),
+        };
+
+        let mut extended = Duration::ZERO;
+        let mut extensions = vec![];
+        for _ in 0..4 {
+            let extension = rules.next(extended);
+            extended += extension;
+            extensions.push(extension.as_secs());
+        }
+
+        assert_eq!(extensions, vec![30, 30, 10, 0]);
+        assert_eq!(extended, rules.max_total);
+    }
+}
@@ -1,13 +1,18 @@
-use std::time::Duration;
-
 use tokio::sync::mpsc;
 
 use crate::{
     mongo_fns::world::{
-        person::{add_person_to_village, count_village_persons, person_name_exists},
-        village::get_village_period,
+        person::{
+            add_person_to_village, count_village_persons, get_all_alive_persons, get_person_name,
+            get_village_persons, person_name_exists, remove_person, set_preferred_role,
+        },
+        village::{ban_person_name, get_village_period, is_person_name_banned},
+    },
+    world::{
+        village::periods::{AssignmentMode, Period, RawPeriod},
+        world_inlet::{FromVillage, LeaveResult, NightChoice},
+        AddPersonResult, WorldInlet,
     },
-    world::{village::periods::RawPeriod, world_inlet::FromVillage, AddPersonResult, WorldInlet},
 };
 
 use super::{inlet_data::VillageInlet, village_info::VillageInfo};
@@ -15,20 +20,67 @@ use super::{inlet_data::VillageInlet, village_info::VillageInfo};
 #[derive(Debug)]
 pub(super) enum VillageInternal {
     PersonsFilled,
-    ExtendPopulationTime(Duration),
+    PersonJoined {
+        person_id: String,
+        current_count: u64,
+    },
+    AssignmentConfigured(AssignmentMode),
+    PersonForfeited(String),
+    Paused,
+    Resumed,
+    Skipped,
+    StartNow,
+    RematchAnswered {
+        person: String,
+        accept: bool,
+    },
     Die,
 
     WolvesVictimSelected(String),
     DoctorTargetSelected(String),
     SeerTargetSelected(String),
+    SerialKillerTargetSelected(String),
+
+    RevealRequested(String),
+    MessagePosted {
+        sender: String,
+        text: String,
+    },
+    LynchVoted {
+        voter: String,
+        target: String,
+    },
+    Nominated {
+        nominator: String,
+        nominee: String,
+    },
+    VerdictVoted {
+        voter: String,
+        guilty: bool,
+    },
 }
 
 impl From<VillageInternal> for SafeVillageInternal {
     fn from(vi: VillageInternal) -> Self {
         match vi {
             VillageInternal::PersonsFilled => SafeVillageInternal::PersonsFilled,
-            VillageInternal::ExtendPopulationTime(e) => {
-                SafeVillageInternal::ExtendPopulationTime(e)
+            VillageInternal::PersonJoined {
+                person_id,
+                current_count,
+            } => SafeVillageInternal::PersonJoined {
+                person_id,
+                current_count,
+            },
+            VillageInternal::AssignmentConfigured(mode) => {
+                SafeVillageInternal::AssignmentConfigured(mode)
+            }
+            VillageInternal::PersonForfeited(s) => SafeVillageInternal::PersonForfeited(s),
+            VillageInternal::Paused => SafeVillageInternal::Paused,
+            VillageInternal::Resumed => SafeVillageInternal::Resumed,
+            VillageInternal::Skipped => SafeVillageInternal::Skipped,
+            VillageInternal::StartNow => SafeVillageInternal::StartNow,
+            VillageInternal::RematchAnswered { person, accept } => {
+                SafeVillageInternal::RematchAnswered { person, accept }
             }
             VillageInternal::Die => panic!("SafeVillageInternal is suppose to filter this."),
             VillageInternal::WolvesVictimSelected(s) => {
@@ -38,17 +90,65 @@ impl From<VillageInternal> for SafeVillageInternal {
                 SafeVillageInternal::DoctorTargetSelected(s)
             }
             VillageInternal::SeerTargetSelected(s) => SafeVillageInternal::SeerTargetSelected(s),
+            VillageInternal::SerialKillerTargetSelected(s) => {
+                SafeVillageInternal::SerialKillerTargetSelected(s)
+            }
+            VillageInternal::RevealRequested(s) => SafeVillageInternal::RevealRequested(s),
+            VillageInternal::MessagePosted { sender, text } => {
+                SafeVillageInternal::MessagePosted { sender, text }
+            }
+            VillageInternal::LynchVoted { voter, target } => {
+                SafeVillageInternal::LynchVoted { voter, target }
+            }
+            VillageInternal::Nominated { nominator, nominee } => {
+                SafeVillageInternal::Nominated { nominator, nominee }
+            }
+            VillageInternal::VerdictVoted { voter, guilty } => {
+                SafeVillageInternal::VerdictVoted { voter, guilty }
+            }
         }
     }
 }
 
 pub(super) enum SafeVillageInternal {
     PersonsFilled,
-    ExtendPopulationTime(Duration),
+    PersonJoined {
+        person_id: String,
+        current_count: u64,
+    },
+    AssignmentConfigured(AssignmentMode),
+    PersonForfeited(String),
+    Paused,
+    Resumed,
+    Skipped,
+    StartNow,
+    RematchAnswered {
+        person: String,
+        accept: bool,
+    },
 
     WolvesVictimSelected(String),
     DoctorTargetSelected(String),
     SeerTargetSelected(String),
+    SerialKillerTargetSelected(String),
+
+    RevealRequested(String),
+    MessagePosted {
+        sender: String,
+        text: String,
+    },
+    LynchVoted {
+        voter: String,
+        target: String,
+    },
+    Nominated {
+        nominator: String,
+        nominee: String,
+    },
+    VerdictVoted {
+        voter: String,
+        guilty: bool,
+    },
 }
 
 pub(super) async fn received_from_world(
@@ -59,7 +159,8 @@ pub(super) async fn received_from_world(
             village_id,
             sender,
             village_name: _,
-            period_maker: _,
+            period_maker,
+            config,
         },
         internal_sender,
         max_persons,
@@ -74,59 +175,266 @@ pub(super) async fn received_from_world(
     };
 
     match received {
-        VillageInlet::AddPerson(name) => match get_village_period(&client, &village_id).await {
-            Some(period) => match period {
-                RawPeriod::Populating => {
-                    let current_person_count = count_village_persons(&client, &village_id).await;
-                    if current_person_count < max_persons.into() {
-                        if person_name_exists(&client, &village_id, &name).await {
-                            send_to_world(AddPerson(AddPersonResult::Failed(
-                                "The person name is duplicated".to_string(),
-                            )))
-                            .await
-                            .unwrap_or(())
-                        } else if let Some(pr) =
-                            add_person_to_village(&client, &village_id, name.as_str()).await
-                        {
-                            send_to_world(AddPerson(AddPersonResult::Added {
-                                person_id: pr.get_id(),
-                                current_count: current_person_count + 1,
-                            }))
-                            .await
-                            .unwrap_or(());
-
-                            if current_person_count + 1 >= max_persons.into() {
+        VillageInlet::AddPerson(name) => {
+            if let Some(period) = get_village_period(&client, &village_id).await {
+                match period {
+                    RawPeriod::Populating => {
+                        let current_person_count =
+                            count_village_persons(&client, &village_id).await;
+                        if current_person_count < max_persons.into() {
+                            if is_person_name_banned(&client, &village_id, &name).await {
+                                send_to_world(AddPerson(AddPersonResult::Failed(
+                                    "You're banned from this village.".to_string(),
+                                )))
+                                .await
+                                .unwrap_or(())
+                            } else if person_name_exists(&client, &village_id, &name).await {
+                                send_to_world(AddPerson(AddPersonResult::Failed(
+                                    "The person name is duplicated".to_string(),
+                                )))
+                                .await
+                                .unwrap_or(())
+                            } else if let Some(pr) =
+                                add_person_to_village(&client, &village_id, name.as_str()).await
+                            {
+                                // Village reports it, with the extended deadline.
                                 internal_sender
-                                    .send(VillageInternal::PersonsFilled)
+                                    .send(VillageInternal::PersonJoined {
+                                        person_id: pr.get_id(),
+                                        current_count: current_person_count + 1,
+                                    })
                                     .await
                                     .unwrap_or(());
+
+                                if current_person_count + 1 >= max_persons.into() {
+                                    internal_sender
+                                        .send(VillageInternal::PersonsFilled)
+                                        .await
+                                        .unwrap_or(());
+                                }
+                            } else {
+                                send_to_world(AddPerson(AddPersonResult::Failed(
+                                    "Error while inserting person.".to_string(),
+                                )))
+                                .await
+                                .unwrap_or(());
                             }
                         } else {
-                            send_to_world(AddPerson(AddPersonResult::Failed(
-                                "Error while inserting person.".to_string(),
-                            )))
-                            .await
-                            .unwrap_or(());
+                            internal_sender
+                                .send(VillageInternal::PersonsFilled)
+                                .await
+                                .unwrap_or(());
                         }
-                    } else {
-                        internal_sender
-                            .send(VillageInternal::PersonsFilled)
-                            .await
-                            .unwrap_or(());
                     }
+                    _ => send_to_world(AddPerson(AddPersonResult::Failed(
+                        "Not populating!".to_string(),
+                    )))
+                    .await
+                    .unwrap_or(()),
+                }
+            }
+        }
+        VillageInlet::LeaveVillage(person_id) => {
+            match get_village_period(&client, &village_id).await {
+                Some(RawPeriod::Populating) => {
+                    let result = match remove_person(&client, &village_id, &person_id).await {
+                        Ok(true) => LeaveResult::Left {
+                            person_id,
+                            current_count: count_village_persons(&client, &village_id).await,
+                        },
+                        Ok(false) => LeaveResult::Failed("You're not in this village.".to_string()),
+                        Err(_) => LeaveResult::Failed("Error while removing person.".to_string()),
+                    };
+                    send_to_world(LeaveVillage(result)).await.unwrap_or(())
+                }
+                Some(RawPeriod::Ending) | Some(RawPeriod::None) | None => send_to_world(
+                    LeaveVillage(LeaveResult::Failed("The game is not running.".to_string())),
+                )
+                .await
+                .unwrap_or(()),
+                // The game is running, leaving is a forfeit.
+                Some(_) => internal_sender
+                    .send(VillageInternal::PersonForfeited(person_id))
+                    .await
+                    .unwrap_or(()),
+            }
+        }
+        VillageInlet::NightChoice(choice) => {
+            let target_alive = get_all_alive_persons(&client, &village_id)
+                .await
+                .iter()
+                .any(|p| p.get_id() == choice.target());
+
+            match target_alive {
+                true => internal_sender
+                    .send(match choice {
+                        NightChoice::Wolves(target) => {
+                            VillageInternal::WolvesVictimSelected(target)
+                        }
+                        NightChoice::Doctor(target) => {
+                            VillageInternal::DoctorTargetSelected(target)
+                        }
+                        NightChoice::Seer(target) => VillageInternal::SeerTargetSelected(target),
+                        NightChoice::SerialKiller(target) => {
+                            VillageInternal::SerialKillerTargetSelected(target)
+                        }
+                    })
+                    .await
+                    .unwrap_or(()),
+                false => send_to_world(NightChoiceRejected(
+                    "Only alive persons of this village can be chosen.".to_string(),
+                ))
+                .await
+                .unwrap_or(()),
+            }
+        }
+        VillageInlet::Reveal(person_id) => internal_sender
+            .send(VillageInternal::RevealRequested(person_id))
+            .await
+            .unwrap_or(()),
+        VillageInlet::Message { sender, text } => internal_sender
+            .send(VillageInternal::MessagePosted { sender, text })
+            .await
+            .unwrap_or(()),
+        VillageInlet::LynchVote { voter, target } => internal_sender
+            .send(VillageInternal::LynchVoted { voter, target })
+            .await
+            .unwrap_or(()),
+        VillageInlet::ConfigureAssignment(mode) => {
+            match get_village_period(&client, &village_id).await {
+                Some(RawPeriod::Populating) => internal_sender
+                    .send(VillageInternal::AssignmentConfigured(mode))
+                    .await
+                    .unwrap_or(()),
+                _ => send_to_world(AssignmentRejected(
+                    "Assignments can be configured only while populating.".to_string(),
+                ))
+                .await
+                .unwrap_or(()),
+            }
+        }
+        VillageInlet::PreferRole { person, role } => {
+            let populating = matches!(
+                get_village_period(&client, &village_id).await,
+                Some(RawPeriod::Populating)
+            );
+            let joined = get_village_persons(&client, &village_id)
+                .await
+                .iter()
+                .any(|p| p.get_id() == person);
+
+            let result = if !populating {
+                Err("Roles can be requested only while populating.".to_string())
+            } else if !joined {
+                Err("You're not in this village.".to_string())
+            } else {
+                match set_preferred_role(&client, &person, role).await {
+                    Ok(_) => Ok(role),
+                    Err(_) => Err("Error while saving the preference.".to_string()),
                 }
-                _ => send_to_world(AddPerson(AddPersonResult::Failed(
-                    "Not populating!".to_string(),
+            };
+
+            send_to_world(RolePreferred {
+                person_id: person,
+                result,
+            })
+            .await
+            .unwrap_or(())
+        }
+        VillageInlet::Nominate { nominator, nominee } => internal_sender
+            .send(VillageInternal::Nominated { nominator, nominee })
+            .await
+            .unwrap_or(()),
+        VillageInlet::Verdict { voter, guilty } => internal_sender
+            .send(VillageInternal::VerdictVoted { voter, guilty })
+            .await
+            .unwrap_or(()),
+        VillageInlet::Pause => internal_sender
+            .send(VillageInternal::Paused)
+            .await
+            .unwrap_or(()),
+        VillageInlet::Resume => internal_sender
+            .send(VillageInternal::Resumed)
+            .await
+            .unwrap_or(()),
+        VillageInlet::Skip => {
+            let min_persons = match period_maker(&RawPeriod::Populating) {
+                Period::Populating { min_persons, .. } => min_persons,
+                _ => 0,
+            };
+            let populating = matches!(
+                get_village_period(&client, &village_id).await,
+                Some(RawPeriod::Populating)
+            );
+
+            // Populating ends early only if there are enough persons to start.
+            if populating && count_village_persons(&client, &village_id).await < min_persons.into()
+            {
+                send_to_world(SkipRejected(format!(
+                    "At least {min_persons} persons are needed to start."
                 )))
+                .await
+                .unwrap_or(())
+            } else {
+                internal_sender
+                    .send(VillageInternal::Skipped)
+                    .await
+                    .unwrap_or(())
+            }
+        }
+        VillageInlet::StartNow => match get_village_period(&client, &village_id).await {
+            Some(RawPeriod::Populating) => internal_sender
+                .send(VillageInternal::StartNow)
+                .await
+                .unwrap_or(()),
+            _ => send_to_world(StartRejected("The village is not populating.".to_string()))
                 .await
                 .unwrap_or(()),
-            },
-            None => (),
         },
-        VillageInlet::ExtendPopulationTime(time) => internal_sender
-            .send(VillageInternal::ExtendPopulationTime(time))
+        VillageInlet::Kick { person, ban } => {
+            let populating = matches!(
+                get_village_period(&client, &village_id).await,
+                Some(RawPeriod::Populating)
+            );
+
+            let result = if !populating {
+                Err("Persons can be kicked only while populating.".to_string())
+            } else {
+                match get_person_name(&client, &village_id, &person).await {
+                    Some(name) => {
+                        let kicked = remove_person(&client, &village_id, &person).await.is_ok()
+                            && (!ban || ban_person_name(&client, &village_id, &name).await.is_ok());
+                        match kicked {
+                            true => Ok(count_village_persons(&client, &village_id).await),
+                            false => Err("Error while kicking person.".to_string()),
+                        }
+                    }
+                    None => Err("No such person in this village.".to_string()),
+                }
+            };
+
+            match result {
+                Ok(current_count) => send_to_world(PersonKicked {
+                    person_id: person,
+                    banned: ban,
+                    current_count,
+                }),
+                Err(reason) => send_to_world(KickRejected(reason)),
+            }
             .await
-            .unwrap_or(()),
+            .unwrap_or(())
+        }
+        VillageInlet::Rematch { person, accept } => {
+            match get_village_period(&client, &village_id).await {
+                Some(RawPeriod::Ending) if config.rematch.is_some() => internal_sender
+                    .send(VillageInternal::RematchAnswered { person, accept })
+                    .await
+                    .unwrap_or(()),
+                _ => send_to_world(RematchRejected("No rematch is offered.".to_string()))
+                    .await
+                    .unwrap_or(()),
+            }
+        }
         VillageInlet::Die => internal_sender.send(VillageInternal::Die).await.unwrap(),
         VillageInlet::RawString(s) => send_to_world(RawString(s)).await.unwrap_or(()),
     };
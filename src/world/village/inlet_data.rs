@@ -1,9 +1,48 @@
-use std::time::Duration;
+use crate::world::{
+    person::roles::Role, village::periods::AssignmentMode, world_inlet::NightChoice,
+};
 
 #[derive(Debug)]
 pub enum VillageInlet {
     RawString(String),
     AddPerson(String),
-    ExtendPopulationTime(Duration),
+    LeaveVillage(String),
+    NightChoice(NightChoice),
+    Reveal(String),
+    Message {
+        sender: String,
+        text: String,
+    },
+    LynchVote {
+        voter: String,
+        target: String,
+    },
+    ConfigureAssignment(AssignmentMode),
+    PreferRole {
+        person: String,
+        role: Role,
+    },
+    Nominate {
+        nominator: String,
+        nominee: String,
+    },
+    Verdict {
+        voter: String,
+        guilty: bool,
+    },
+    Pause,
+    Resume,
+    Skip,
+    StartNow,
+    /// Removes a person while populating, `ban` to keep his name out.
+    Kick {
+        person: String,
+        ban: bool,
+    },
+    /// Answer to a rematch offer, `accept` to play again.
+    Rematch {
+        person: String,
+        accept: bool,
+    },
     Die,
 }
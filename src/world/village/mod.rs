@@ -1,3 +1,4 @@
+pub mod config;
 mod handle_from_world;
 pub mod inlet_data;
 pub mod periods;
@@ -10,6 +11,7 @@ use mongodb::Client;
 use tokio::sync::mpsc::{channel, error::SendError, Sender};
 
 use self::{
+    config::VillageConfig,
     inlet_data::VillageInlet,
     periods::{Period, RawPeriod},
     simplified_village::SimplifiedVillage,
@@ -17,13 +19,16 @@ use self::{
     village_main::VillageMain,
 };
 
-use super::WorldInlet;
+use super::{world_inlet::Caller, WorldInlet};
 
 #[derive(Clone)]
 pub struct Village {
     village_id: String,
     village_name: String,
+    /// Who created the village, allowed to manage it.
+    host: Caller,
     current_period: Period,
+    config: VillageConfig,
     pub(crate) sender: Sender<VillageInlet>,
 }
 
@@ -31,7 +36,9 @@ impl Village {
     pub fn new(
         client: Client,
         village_name: &str,
+        host: Caller,
         period_maker: fn(&RawPeriod) -> Period,
+        config: VillageConfig,
         to_world_sender: Sender<WorldInlet>,
     ) -> Self {
         let village_id: String = uuid::Uuid::new_v4().to_string();
@@ -40,7 +47,9 @@ impl Village {
         let village = Village {
             village_id: village_id.clone(),
             village_name: village_name.to_string(),
+            host,
             current_period: Period::None,
+            config: config.clone(),
             sender: inlet_tx,
         };
 
@@ -50,6 +59,7 @@ impl Village {
             village_name: village_name.to_string(),
             sender: to_world_sender,
             period_maker,
+            config,
         };
 
         tokio::spawn(async move {
@@ -69,7 +79,15 @@ impl Village {
     }
 
     pub fn get_current_period(&self) -> Period {
-        self.current_period
+        self.current_period.clone()
+    }
+
+    pub fn get_host(&self) -> &Caller {
+        &self.host
+    }
+
+    pub fn get_config(&self) -> &VillageConfig {
+        &self.config
     }
 
     pub(crate) fn set_current_period(&mut self, period: Period) {
@@ -1,8 +1,16 @@
 use std::{fmt::Display, time::Duration};
 
-#[derive(Debug, Clone, Copy)]
+use crate::world::person::roles::Role;
+
+#[derive(Debug, Clone)]
 pub enum AssignmentMode {
     Normal,
+    /// Random roles, while keeping teams balanced.
+    Chaos,
+    /// Exactly these roles, given by the host.
+    Custom(Vec<Role>),
+    /// Like [`AssignmentMode::Normal`], but roles requested by persons are honoured when possible.
+    Preferences,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -10,6 +18,8 @@ pub enum Daytime {
     MidNight,
     SunRaise,
     LynchTime,
+    /// A quiet talk after the lynch, nothing else happens.
+    Dusk,
 }
 
 impl Display for Daytime {
@@ -18,17 +28,7 @@ impl Display for Daytime {
             Daytime::MidNight => write!(f, "🌃 Mid Night"),
             Daytime::SunRaise => write!(f, "🌇 Sun Raise"),
             Daytime::LynchTime => write!(f, "⚔️ Lynch Time"),
-        }
-    }
-}
-
-impl Daytime {
-    pub fn cross(&self) -> Self {
-        use Daytime::*;
-        match *self {
-            MidNight => SunRaise,
-            SunRaise => LynchTime,
-            LynchTime => MidNight,
+            Daytime::Dusk => write!(f, "🌆 Dusk"),
         }
     }
 }
@@ -43,18 +43,127 @@ pub enum RawPeriod {
     Ending,
 }
 
-impl RawPeriod {
-    #[allow(clippy::result_unit_err)]
-    pub fn cross(&self) -> Result<Self, ()> {
-        use RawPeriod::*;
-        match *self {
-            None => Ok(Populating),
-            Populating => Ok(Assignments),
-            Assignments => Ok(FirstNight),
-            FirstNight => Ok(DaytimeCycle),
-            DaytimeCycle => Ok(Ending),
-            Ending => Err(()),
+/// Periods a village goes through, in order.
+#[derive(Debug, Clone)]
+pub struct PeriodPlan {
+    pub periods: Vec<RawPeriod>,
+    /// Daytimes of the daytime cycle, in order. The cycle repeats them.
+    pub daytimes: Vec<Daytime>,
+    /// The game ends after this many cycles, `None` to play until someone wins.
+    pub max_cycles: Option<u32>,
+}
+
+impl PeriodPlan {
+    /// A plan of the host's own, checked with [`PeriodPlan::validate`].
+    ///
+    /// ## Errors
+    ///
+    /// Returns the reason if a village can't play this plan.
+    pub fn new(
+        periods: Vec<RawPeriod>,
+        daytimes: Vec<Daytime>,
+        max_cycles: Option<u32>,
+    ) -> Result<Self, String> {
+        let plan = Self {
+            periods,
+            daytimes,
+            max_cycles,
+        };
+        plan.validate()?;
+        Ok(plan)
+    }
+
+    /// Populating → Assignments → FirstNight → DaytimeCycle → Ending, with daytimes of the host.
+    pub fn custom(daytimes: Vec<Daytime>, max_cycles: Option<u32>) -> Result<Self, String> {
+        Self::new(Self::classic().periods, daytimes, max_cycles)
+    }
+
+    /// Checks if a village can play this plan.
+    ///
+    /// ## Errors
+    ///
+    /// Returns the reason if it can't.
+    pub fn validate(&self) -> Result<(), String> {
+        let position = |period: RawPeriod| {
+            self.periods
+                .iter()
+                .position(|planned| i32::from(*planned) == i32::from(period))
+        };
+
+        if self
+            .periods
+            .iter()
+            .enumerate()
+            .any(|(at, period)| position(*period) != Some(at))
+        {
+            return Err("Each period can be planned once.".to_string());
+        }
+
+        // No roles without persons, no game without roles.
+        let populating = position(RawPeriod::Populating);
+        let assignments = position(RawPeriod::Assignments);
+        if assignments.is_some() && (populating.is_none() || populating > assignments) {
+            return Err("Populating should come before Assignments.".to_string());
+        }
+        for played in [RawPeriod::FirstNight, RawPeriod::DaytimeCycle] {
+            if position(played).is_some()
+                && (assignments.is_none() || assignments > position(played))
+            {
+                return Err("Assignments should come before nights and days.".to_string());
+            }
+        }
+
+        if position(RawPeriod::DaytimeCycle).is_some() && self.daytimes.is_empty() {
+            return Err("The daytime cycle needs at least one daytime.".to_string());
+        }
+        if self.max_cycles == Some(0) {
+            return Err("Play at least one cycle.".to_string());
         }
+
+        Ok(())
+    }
+
+    /// Populating → Assignments → FirstNight → DaytimeCycle → Ending, until someone wins.
+    pub fn classic() -> Self {
+        Self {
+            periods: vec![
+                RawPeriod::Populating,
+                RawPeriod::Assignments,
+                RawPeriod::FirstNight,
+                RawPeriod::DaytimeCycle,
+                RawPeriod::Ending,
+            ],
+            daytimes: vec![Daytime::SunRaise, Daytime::LynchTime, Daytime::MidNight],
+            max_cycles: None,
+        }
+    }
+
+    /// Exactly one night and one lynch, then the game ends.
+    pub fn quick() -> Self {
+        Self {
+            periods: vec![
+                RawPeriod::Populating,
+                RawPeriod::Assignments,
+                RawPeriod::DaytimeCycle,
+                RawPeriod::Ending,
+            ],
+            daytimes: vec![Daytime::MidNight, Daytime::SunRaise, Daytime::LynchTime],
+            max_cycles: Some(1),
+        }
+    }
+
+    /// The period after `current`, the first one for [`RawPeriod::None`].
+    pub fn next(&self, current: &RawPeriod) -> Option<RawPeriod> {
+        let current: i32 = (*current).into();
+        if current == i32::from(RawPeriod::None) {
+            return self.periods.first().copied();
+        }
+
+        let found = self
+            .periods
+            .iter()
+            .position(|period| i32::from(*period) == current)?;
+        self.periods.get(found + 1).copied()
     }
 }
 
@@ -86,7 +195,7 @@ impl From<i32> for RawPeriod {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub enum Period {
     None,
     Populating {
@@ -96,7 +205,11 @@ pub enum Period {
     },
     Assignments(AssignmentMode),
     FirstNight(Duration),
-    DaytimeCycle(fn(Daytime) -> Duration),
+    DaytimeCycle {
+        daytime_dur: fn(Daytime) -> Duration,
+        /// How long a person can talk right after he died.
+        last_words_dur: Duration,
+    },
     Ending,
 }
 
@@ -112,8 +225,49 @@ impl From<Period> for RawPeriod {
             } => RawPeriod::Populating,
             Assignments(_) => RawPeriod::Assignments,
             FirstNight(_) => RawPeriod::FirstNight,
-            DaytimeCycle(_) => RawPeriod::DaytimeCycle,
+            DaytimeCycle {
+                daytime_dur: _,
+                last_words_dur: _,
+            } => RawPeriod::DaytimeCycle,
             Ending => RawPeriod::Ending,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn presets_are_valid() {
+        assert!(PeriodPlan::classic().validate().is_ok());
+        assert!(PeriodPlan::quick().validate().is_ok());
+        assert!(PeriodPlan::custom(vec![Daytime::LynchTime, Daytime::Dusk], Some(2)).is_ok());
+    }
+
+    #[test]
+    fn broken_plans() {
+        // No one joins, no one gets a role.
+        assert!(PeriodPlan::new(
+            vec![RawPeriod::Assignments, RawPeriod::Ending],
+            vec![],
+            None
+        )
+        .is_err());
+        assert!(PeriodPlan::new(
+            vec![RawPeriod::Populating, RawPeriod::DaytimeCycle],
+            vec![Daytime::MidNight],
+            None
+        )
+        .is_err());
+        assert!(PeriodPlan::new(
+            vec![RawPeriod::Populating, RawPeriod::Populating],
+            vec![],
+            None
+        )
+        .is_err());
+
+        assert!(PeriodPlan::custom(vec![], None).is_err());
+        assert!(PeriodPlan::custom(vec![Daytime::MidNight], Some(0)).is_err());
+    }
+}
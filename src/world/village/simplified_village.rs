@@ -1,10 +1,12 @@
-use std::time::Duration;
-
 use tokio::sync::mpsc::error::SendError;
 
-use crate::world::world_outlet::VillageLiteInfo;
+use crate::world::{
+    person::roles::Role,
+    world_inlet::{Caller, NightChoice},
+    world_outlet::VillageLiteInfo,
+};
 
-use super::{inlet_data::VillageInlet, Village};
+use super::{inlet_data::VillageInlet, periods::AssignmentMode, Village};
 
 #[derive(Clone)]
 pub struct SimplifiedVillage {
@@ -26,6 +28,11 @@ impl SimplifiedVillage {
         self.village.village_name.as_ref()
     }
 
+    /// Returns a reference to the host of this [`Village`].
+    pub fn get_host(&self) -> &Caller {
+        self.village.get_host()
+    }
+
     /// Returns the add player's id of this [`Village`].
     ///
     /// # Errors
@@ -37,12 +44,91 @@ impl SimplifiedVillage {
             .await
     }
 
-    pub async fn extend_population_dur(
+    pub async fn leave(&self, person_id: &str) -> Result<(), SendError<VillageInlet>> {
+        self.village
+            .transmit(VillageInlet::LeaveVillage(person_id.to_string()))
+            .await
+    }
+
+    pub async fn night_choice(&self, choice: NightChoice) -> Result<(), SendError<VillageInlet>> {
+        self.village
+            .transmit(VillageInlet::NightChoice(choice))
+            .await
+    }
+
+    pub async fn reveal(&self, person_id: &str) -> Result<(), SendError<VillageInlet>> {
+        self.village
+            .transmit(VillageInlet::Reveal(person_id.to_string()))
+            .await
+    }
+
+    pub async fn send_message(
         &self,
-        dur: Duration,
+        sender: &str,
+        text: &str,
     ) -> Result<(), SendError<VillageInlet>> {
         self.village
-            .transmit(VillageInlet::ExtendPopulationTime(dur))
+            .transmit(VillageInlet::Message {
+                sender: sender.to_string(),
+                text: text.to_string(),
+            })
+            .await
+    }
+
+    pub async fn lynch_vote(
+        &self,
+        voter: &str,
+        target: &str,
+    ) -> Result<(), SendError<VillageInlet>> {
+        self.village
+            .transmit(VillageInlet::LynchVote {
+                voter: voter.to_string(),
+                target: target.to_string(),
+            })
+            .await
+    }
+
+    pub async fn configure_assignment(
+        &self,
+        mode: AssignmentMode,
+    ) -> Result<(), SendError<VillageInlet>> {
+        self.village
+            .transmit(VillageInlet::ConfigureAssignment(mode))
+            .await
+    }
+
+    pub async fn prefer_role(
+        &self,
+        person: &str,
+        role: Role,
+    ) -> Result<(), SendError<VillageInlet>> {
+        self.village
+            .transmit(VillageInlet::PreferRole {
+                person: person.to_string(),
+                role,
+            })
+            .await
+    }
+
+    pub async fn nominate(
+        &self,
+        nominator: &str,
+        nominee: &str,
+    ) -> Result<(), SendError<VillageInlet>> {
+        self.village
+            .transmit(VillageInlet::Nominate {
+                nominator: nominator.to_string(),
+                nominee: nominee.to_string(),
+            })
+            .await
+    }
+
+    pub async fn verdict(&self, voter: &str, guilty: bool) -> Result<(), SendError<VillageInlet>> {
+        self.village
+            .transmit(VillageInlet::Verdict {
+                voter: voter.to_string(),
+                guilty,
+            })
             .await
     }
 
@@ -50,6 +136,44 @@ impl SimplifiedVillage {
         self.village.transmit(VillageInlet::Die).await
     }
 
+    pub async fn pause(&self) -> Result<(), SendError<VillageInlet>> {
+        self.village.transmit(VillageInlet::Pause).await
+    }
+
+    pub async fn resume(&self) -> Result<(), SendError<VillageInlet>> {
+        self.village.transmit(VillageInlet::Resume).await
+    }
+
+    pub async fn skip_phase(&self) -> Result<(), SendError<VillageInlet>> {
+        self.village.transmit(VillageInlet::Skip).await
+    }
+
+    pub async fn start_now(&self) -> Result<(), SendError<VillageInlet>> {
+        self.village.transmit(VillageInlet::StartNow).await
+    }
+
+    pub async fn kick(&self, person_id: &str, ban: bool) -> Result<(), SendError<VillageInlet>> {
+        self.village
+            .transmit(VillageInlet::Kick {
+                person: person_id.to_string(),
+                ban,
+            })
+            .await
+    }
+
+    pub async fn answer_rematch(
+        &self,
+        person_id: &str,
+        accept: bool,
+    ) -> Result<(), SendError<VillageInlet>> {
+        self.village
+            .transmit(VillageInlet::Rematch {
+                person: person_id.to_string(),
+                accept,
+            })
+            .await
+    }
+
     pub fn get_info(&self) -> VillageLiteInfo {
         VillageLiteInfo::new(self.get_village_id(), self.get_village_name())
     }
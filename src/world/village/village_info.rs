@@ -3,7 +3,10 @@ use tokio::sync::mpsc::Sender;
 
 use crate::world::WorldInlet;
 
-use super::periods::{Period, RawPeriod};
+use super::{
+    config::VillageConfig,
+    periods::{Period, RawPeriod},
+};
 
 #[derive(Clone)]
 pub(super) struct VillageInfo {
@@ -12,6 +15,7 @@ pub(super) struct VillageInfo {
     pub(super) village_name: String,
     pub(super) sender: Sender<WorldInlet>,
     pub(super) period_maker: fn(&RawPeriod) -> Period,
+    pub(super) config: VillageConfig,
 }
 
 impl VillageInfo {
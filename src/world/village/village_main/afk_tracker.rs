@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+
+use crate::world::village::config::AfkRules;
+
+/// What happens to a person after he missed an action.
+#[derive(Debug, PartialEq, Eq)]
+pub(super) enum AfkStatus {
+    Active,
+    /// One more missed action and he's gone.
+    Warned(u8),
+    Gone,
+}
+
+/// Missed actions of each person in a row, acting resets them.
+pub(super) struct AfkTracker {
+    missed: HashMap<String, u8>,
+}
+
+impl AfkTracker {
+    pub(super) fn new() -> Self {
+        Self {
+            missed: HashMap::new(),
+        }
+    }
+
+    pub(super) fn acted(&mut self, person_id: &str) {
+        self.missed.remove(person_id);
+    }
+
+    /// Registers a missed action, the person is gone after `max_missed` of them.
+    ///
+    /// Never less than [`AfkRules::MIN_MISSED`], no one is gone without a warning.
+    pub(super) fn missed(&mut self, person_id: &str, max_missed: u8) -> AfkStatus {
+        let max_missed = max_missed.max(AfkRules::MIN_MISSED);
+        let missed = self.missed.entry(person_id.to_string()).or_default();
+        *missed += 1;
+
+        if *missed >= max_missed {
+            self.missed.remove(person_id);
+            AfkStatus::Gone
+        } else if *missed + 1 == max_missed {
+            AfkStatus::Warned(*missed)
+        } else {
+            AfkStatus::Active
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn warned_then_gone() {
+        let mut tracker = AfkTracker::new();
+        assert_eq!(tracker.missed("a", 3), AfkStatus::Active);
+        assert_eq!(tracker.missed("a", 3), AfkStatus::Warned(2));
+
+        // Acting gives a fresh start.
+        tracker.acted("a");
+        assert_eq!(tracker.missed("a", 3), AfkStatus::Active);
+        assert_eq!(tracker.missed("a", 3), AfkStatus::Warned(2));
+        assert_eq!(tracker.missed("a", 3), AfkStatus::Gone);
+
+        // Too low limits still warn first.
+        for max_missed in [0, 1, 2] {
+            assert_eq!(tracker.missed("b", max_missed), AfkStatus::Warned(1));
+            assert_eq!(tracker.missed("b", max_missed), AfkStatus::Gone);
+        }
+    }
+}
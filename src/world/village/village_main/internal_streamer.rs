@@ -2,7 +2,10 @@ use std::time::Duration;
 
 use tokio::time::{timeout, Instant};
 
-use crate::world::village::handle_from_world::SafeVillageInternal;
+use crate::world::{
+    village::{handle_from_world::SafeVillageInternal, periods::RawPeriod},
+    world_inlet::{AddPersonResult, FromVillage},
+};
 
 use super::VillageMain;
 
@@ -10,6 +13,8 @@ use super::VillageMain;
 pub(super) enum ExitFlag {
     NotExited,
     TimedOut,
+    /// Phase ended by an admin, as if it timed out.
+    Skipped,
     VillageDead,
 }
 
@@ -30,25 +35,151 @@ impl<'r> InternalStreamer<'r> {
         }
     }
 
+    /// Next internal data, chat messages and forfeits are handled here and never returned.
+    ///
+    /// So are joins once populating is over, populating itself extends its deadline first.
     pub(super) async fn next(&mut self) -> Result<SafeVillageInternal, ExitFlag> {
-        self.timeout -= self.elapsed;
+        loop {
+            match self.next_raw().await? {
+                SafeVillageInternal::MessagePosted { sender, text } => {
+                    self.village_main.post_message(&sender, &text).await
+                }
+                SafeVillageInternal::PersonForfeited(person_id) => {
+                    self.village_main.forfeit(&person_id).await
+                }
+                SafeVillageInternal::PersonJoined {
+                    person_id,
+                    current_count,
+                } if !matches!(self.village_main.current_period_raw, RawPeriod::Populating) => self
+                    .village_main
+                    .notify(FromVillage::AddPerson(AddPersonResult::Added {
+                        person_id,
+                        current_count,
+                        time_left: Duration::ZERO,
+                    }))
+                    .await
+                    .unwrap_or_default(),
+                SafeVillageInternal::Paused => self.pause().await?,
+                SafeVillageInternal::Skipped => return Err(self.skip().await),
+                SafeVillageInternal::Resumed => self
+                    .village_main
+                    .notify(FromVillage::PauseRejected(
+                        "The village is not paused.".to_string(),
+                    ))
+                    .await
+                    .unwrap_or_default(),
+                data => return Ok(data),
+            }
+        }
+    }
+
+    /// Freezes the countdown until resumed, chat and forfeits go on meanwhile.
+    ///
+    /// Votes and night choices are rejected, anything else waits for the resume.
+    async fn pause(&mut self) -> Result<(), ExitFlag> {
+        let remaining = self.timeout.saturating_sub(self.elapsed);
+        self.village_main
+            .notify(FromVillage::Paused { remaining })
+            .await
+            .unwrap_or_default();
+
+        loop {
+            match self.village_main.listen_to_new_internals().await {
+                Some(SafeVillageInternal::Resumed) => break,
+                Some(SafeVillageInternal::Paused) => self
+                    .village_main
+                    .notify(FromVillage::PauseRejected(
+                        "The village is already paused.".to_string(),
+                    ))
+                    .await
+                    .unwrap_or_default(),
+                Some(SafeVillageInternal::MessagePosted { sender, text }) => {
+                    self.village_main.post_message(&sender, &text).await
+                }
+                Some(SafeVillageInternal::PersonForfeited(person_id)) => {
+                    self.village_main.forfeit(&person_id).await
+                }
+                Some(
+                    SafeVillageInternal::LynchVoted { .. }
+                    | SafeVillageInternal::Nominated { .. }
+                    | SafeVillageInternal::VerdictVoted { .. }
+                    | SafeVillageInternal::WolvesVictimSelected(_)
+                    | SafeVillageInternal::DoctorTargetSelected(_)
+                    | SafeVillageInternal::SeerTargetSelected(_)
+                    | SafeVillageInternal::SerialKillerTargetSelected(_),
+                ) => self
+                    .village_main
+                    .notify(FromVillage::PauseRejected(
+                        "The village is paused, act again once resumed.".to_string(),
+                    ))
+                    .await
+                    .unwrap_or_default(),
+                // Skipping a paused phase, resume and end it.
+                Some(SafeVillageInternal::Skipped) => {
+                    self.village_main
+                        .notify(FromVillage::Resumed { remaining })
+                        .await
+                        .unwrap_or_default();
+                    return Err(self.skip().await);
+                }
+                Some(data) => self.village_main.held.push_back(data),
+                None => {
+                    self.exit_err = ExitFlag::VillageDead;
+                    return Err(ExitFlag::VillageDead);
+                }
+            }
+        }
+
+        self.village_main
+            .notify(FromVillage::Resumed { remaining })
+            .await
+            .unwrap_or_default();
+        Ok(())
+    }
+
+    async fn skip(&mut self) -> ExitFlag {
+        self.village_main
+            .notify(FromVillage::PhaseSkipped)
+            .await
+            .unwrap_or_default();
+
+        self.exit_err = ExitFlag::Skipped;
+        ExitFlag::Skipped
+    }
+
+    async fn next_raw(&mut self) -> Result<SafeVillageInternal, ExitFlag> {
+        self.timeout = self.timeout.saturating_sub(self.elapsed);
         self.elapsed = Duration::ZERO;
 
         let start = Instant::now();
-        match timeout(self.timeout, self.village_main.listen_to_safe_internals()).await {
-            Ok(data) => {
-                self.elapsed = start.elapsed();
-                match data {
-                    Some(data) => Ok(data),
+        loop {
+            let remaining = self.timeout.saturating_sub(start.elapsed());
+            // Wake up at the next reminder, if there's one before the timeout.
+            let reminder = next_reminder(&self.village_main.info.config.reminders, remaining);
+            let wait = remaining - reminder.unwrap_or_default();
+
+            match timeout(wait, self.village_main.listen_to_safe_internals()).await {
+                Ok(data) => {
+                    self.elapsed = start.elapsed();
+                    return match data {
+                        Some(data) => Ok(data),
+                        None => {
+                            self.exit_err = ExitFlag::VillageDead;
+                            Err(ExitFlag::VillageDead)
+                        }
+                    };
+                }
+                Err(_) => match reminder {
+                    Some(remaining) => self
+                        .village_main
+                        .notify(FromVillage::TimeReminder { remaining })
+                        .await
+                        .unwrap_or_default(),
                     None => {
-                        self.exit_err = ExitFlag::VillageDead;
-                        Err(ExitFlag::VillageDead)
+                        self.exit_err = ExitFlag::TimedOut;
+                        return Err(ExitFlag::TimedOut);
                     }
-                }
-            }
-            Err(_) => {
-                self.exit_err = ExitFlag::TimedOut;
-                Err(ExitFlag::TimedOut)
+                },
             }
         }
     }
@@ -94,10 +225,26 @@ impl<'r> InternalStreamer<'r> {
         Err(self.exit_err)
     }
 
+    pub(super) async fn wait_for_serial_killer_choice(&mut self) -> Result<String, ExitFlag> {
+        while let Ok(thing) = self.next().await {
+            match thing {
+                SafeVillageInternal::SerialKillerTargetSelected(target) => return Ok(target),
+                _ => continue,
+            }
+        }
+
+        Err(self.exit_err)
+    }
+
     pub(super) fn increase_timeout(&mut self, dur: Duration) {
         self.timeout += dur;
     }
 
+    /// Time left before timing out.
+    pub(super) fn remaining(&self) -> Duration {
+        self.timeout.saturating_sub(self.elapsed)
+    }
+
     pub(super) fn vg(&mut self) -> &mut VillageMain {
         self.village_main
     }
@@ -116,3 +263,54 @@ impl<'r> InternalStreamer<'r> {
         self.exit_err = ExitFlag::NotExited;
     }
 }
+
+/// The largest reminder which is still ahead, with `remaining` time left.
+fn next_reminder(reminders: &[Duration], remaining: Duration) -> Option<Duration> {
+    reminders
+        .iter()
+        .copied()
+        .filter(|reminder| !reminder.is_zero() && *reminder < remaining)
+        .max()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn secs(secs: &[u64]) -> Vec<Duration> {
+        secs.iter().copied().map(Duration::from_secs).collect()
+    }
+
+    #[test]
+    fn next_reminder_is_the_largest_ahead() {
+        let reminders = secs(&[0, 10, 60, 30, 120]);
+        assert_eq!(
+            next_reminder(&reminders, Duration::from_secs(90)),
+            Some(Duration::from_secs(60))
+        );
+        // Exactly at a reminder, it's the current one and not ahead anymore.
+        assert_eq!(
+            next_reminder(&reminders, Duration::from_secs(30)),
+            Some(Duration::from_secs(10))
+        );
+        assert_eq!(next_reminder(&reminders, Duration::from_secs(10)), None);
+        assert_eq!(next_reminder(&[], Duration::from_secs(90)), None);
+    }
+
+    #[test]
+    fn reminders_wake_up_once_each() {
+        let reminders = secs(&[10, 30, 60, 120]);
+
+        // Same steps as `next_raw`, waking up at each reminder until the timeout.
+        let mut remaining = Duration::from_secs(90);
+        let mut reminded = vec![];
+        while let Some(reminder) = next_reminder(&reminders, remaining) {
+            let wait = remaining - reminder;
+            assert!(!wait.is_zero());
+            remaining -= wait;
+            reminded.push(reminder);
+        }
+
+        assert_eq!(reminded, secs(&[60, 30, 10]));
+    }
+}
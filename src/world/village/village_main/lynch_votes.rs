@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+
+/// Lynch votes of the current lynch time, one vote per voter.
+pub(super) struct LynchVotes {
+    votes: HashMap<String, String>,
+}
+
+impl LynchVotes {
+    pub(super) fn new() -> Self {
+        Self {
+            votes: HashMap::new(),
+        }
+    }
+
+    /// Registers or changes the vote of `voter`.
+    pub(super) fn vote(&mut self, voter: &str, target: &str) {
+        self.votes.insert(voter.to_string(), target.to_string());
+    }
+
+    pub(super) fn voters(&self) -> Vec<String> {
+        self.votes.keys().cloned().collect()
+    }
+
+    /// The person with most votes, `None` on a tie or if no one voted.
+    ///
+    /// Each vote counts as much as `weight` of the voter.
+    pub(super) fn lynched<W>(&self, weight: W) -> Option<String>
+    where
+        W: Fn(&str) -> u32,
+    {
+        let mut tally = HashMap::<&str, u32>::new();
+        for (voter, target) in self.votes.iter() {
+            *tally.entry(target.as_str()).or_default() += weight(voter);
+        }
+
+        let max = *tally.values().max()?;
+        let mut top = tally.into_iter().filter(|(_, count)| *count == max);
+        match (top.next(), top.next()) {
+            (Some((target, _)), None) => Some(target.to_string()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn most_voted_is_lynched() {
+        let mut votes = LynchVotes::new();
+        assert_eq!(votes.lynched(|_| 1), None);
+
+        votes.vote("a", "c");
+        votes.vote("b", "c");
+        votes.vote("c", "a");
+        assert_eq!(votes.lynched(|_| 1), Some("c".to_string()));
+
+        // Changed mind, now it's a tie.
+        votes.vote("b", "b");
+        assert_eq!(votes.lynched(|_| 1), None);
+    }
+
+    #[test]
+    fn weighted_votes() {
+        let mut votes = LynchVotes::new();
+        votes.vote("mayor", "a");
+        votes.vote("b", "c");
+        votes.vote("c", "a");
+        votes.vote("d", "c");
+        assert_eq!(votes.lynched(|_| 1), None);
+
+        let weight = |voter: &str| if voter == "mayor" { 2 } else { 1 };
+        assert_eq!(votes.lynched(weight), Some("a".to_string()));
+    }
+}
@@ -1,7 +1,14 @@
+mod afk_tracker;
 mod internal_streamer;
+mod lynch_votes;
 mod night_events_storage;
+mod trial;
+mod win_conditions;
 
-use std::time::Duration;
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    time::Duration,
+};
 
 use mongodb::Client;
 use tokio::{
@@ -12,23 +19,41 @@ use tokio::{
 use crate::{
     mongo_fns::world::{
         person::{
-            assign_roles, cleanup_persons, count_village_persons, get_person_role, mark_dead,
+            assign_roles, change_person_role, cleanup_persons, count_village_persons,
+            get_alive_persons_with_role, get_all_alive_persons, get_person_role,
+            get_village_persons, mark_dead, remove_person, reset_persons,
         },
-        village::{cleanup_village_period, set_or_update_village_period},
+        village::{cleanup_village_bans, cleanup_village_period, set_or_update_village_period},
     },
     world::{
-        person::roles::Role,
+        chat::{ChatChannel, ChatMessage},
+        person::{
+            assigner::assign,
+            balance,
+            roles::{Role, Team},
+            Person,
+        },
         village::{
+            config::{DeathReveal, RematchRules, TrialRules},
             handle_from_world::received_from_world,
-            periods::{Daytime, Period, RawPeriod},
+            periods::{AssignmentMode, Daytime, Period, RawPeriod},
             village_main::{internal_streamer::ExitFlag, night_events_storage::NightEventsStorage},
         },
-        world_inlet::{FromVillage, NightActionResult},
+        world_inlet::{
+            AddPersonResult, DeathCause, FromVillage, GameReport, LeaveResult, LynchResult,
+            NightActionResult, NightChoice, SpectatorEvent,
+        },
         WorldInlet,
     },
 };
 
-use self::internal_streamer::InternalStreamer;
+use self::{
+    afk_tracker::{AfkStatus, AfkTracker},
+    internal_streamer::InternalStreamer,
+    lynch_votes::LynchVotes,
+    trial::{Trial, TrialStage},
+    win_conditions::{evaluate, evaluate_final, GameEvent, Victory},
+};
 
 use super::{
     handle_from_world::{SafeVillageInternal, VillageInternal},
@@ -42,6 +67,20 @@ pub(super) struct VillageMain {
     internal_rx: Receiver<VillageInternal>,
     transporter_handle: JoinHandle<()>,
     current_period_raw: RawPeriod,
+    night_events: NightEventsStorage,
+    victory: Option<Victory>,
+    revealed_persons: HashSet<String>,
+    current_daytime: Option<Daytime>,
+    trial: Trial,
+    /// Persons died since the last words window.
+    dying: Vec<String>,
+    /// The person saying his last words, and whether he already said them.
+    last_words: Option<(String, bool)>,
+    /// Assignment mode configured by the host, instead of the period's.
+    assignment: Option<AssignmentMode>,
+    afk: AfkTracker,
+    /// Received while paused, handled once resumed.
+    held: VecDeque<SafeVillageInternal>,
 }
 
 impl VillageMain {
@@ -70,6 +109,16 @@ impl VillageMain {
             internal_rx,
             transporter_handle,
             current_period_raw,
+            night_events: NightEventsStorage::new(),
+            victory: None,
+            revealed_persons: HashSet::new(),
+            current_daytime: None,
+            trial: Trial::new(),
+            dying: vec![],
+            last_words: None,
+            assignment: None,
+            afk: AfkTracker::new(),
+            held: VecDeque::new(),
         }
     }
 
@@ -93,25 +142,24 @@ impl VillageMain {
         &self.info.sender
     }
 
-    /// Tries to cross the period to the next for this [`VillageMain`].
-    ///
-    /// ## Errors
-    ///
-    /// This function will return an error if the village is in last period already.
-    async fn cross_period(&mut self) -> Result<(), ()> {
-        match self.current_period_raw.cross() {
-            Ok(new) => {
-                self.current_period_raw = new;
-                set_or_update_village_period(
-                    self.get_client(),
-                    self.get_village_id(),
-                    &self.current_period_raw,
-                )
-                .await;
-                Ok(())
-            }
-            Err(_) => Err(()),
-        }
+    /// Moves this [`VillageMain`] to the `new` period.
+    async fn enter_period(&mut self, new: RawPeriod) {
+        self.current_period_raw = new;
+        set_or_update_village_period(
+            self.get_client(),
+            self.get_village_id(),
+            &self.current_period_raw,
+        )
+        .await;
+    }
+
+    /// The period after current one, as the village plan says.
+    fn next_period_raw(&self) -> RawPeriod {
+        self.info
+            .config
+            .plan
+            .next(&self.current_period_raw)
+            .unwrap_or(RawPeriod::Ending)
     }
 
     async fn notify(&self, outlet: FromVillage) -> Result<(), mpsc::error::SendError<WorldInlet>> {
@@ -143,6 +191,7 @@ impl VillageMain {
         let vid = self.get_village_id();
         cleanup_persons(cli, vid).await.unwrap();
         cleanup_village_period(cli, vid).await.unwrap();
+        cleanup_village_bans(cli, vid).await.unwrap();
 
         self.transporter_handle.abort();
     }
@@ -153,6 +202,14 @@ impl VillageMain {
 
     /// If this returns None, the village_main should return.
     async fn listen_to_safe_internals(&mut self) -> Option<SafeVillageInternal> {
+        match self.held.pop_front() {
+            Some(data) => Some(data),
+            None => self.listen_to_new_internals().await,
+        }
+    }
+
+    /// Same as `listen_to_safe_internals`, without the held data.
+    async fn listen_to_new_internals(&mut self) -> Option<SafeVillageInternal> {
         match self.internal_rx.recv().await {
             Some(data) => match data {
                 // Let finish it right now ...
@@ -171,40 +228,332 @@ impl VillageMain {
         }
     }
 
-    async fn assign_roles(&self) -> Vec<crate::world::person::roles::Role> {
-        assign_roles(self.get_client(), self.get_village_id())
+    async fn assign_roles(&self, mode: &AssignmentMode) -> Vec<Role> {
+        assign_roles(
+            self.get_client(),
+            self.get_village_id(),
+            self.get_protector_role(),
+            mode,
+        )
+        .await
+        .unwrap()
+    }
+
+    /// Uses `mode` for the assignments, and reports its balance for a full village.
+    async fn configure_assignment(&mut self, mode: AssignmentMode, max_persons: u8) {
+        let persons = max_persons as usize;
+        let roles = match &mode {
+            AssignmentMode::Custom(list) => list.clone(),
+            _ => assign(
+                &mode,
+                self.get_protector_role(),
+                &vec![Role::NoRole; persons],
+            ),
+        };
+        let balance = balance::evaluate(&roles, persons);
+
+        self.assignment = Some(mode.clone());
+        self.notify(FromVillage::AssignmentConfigured { mode, balance })
             .await
-            .unwrap()
+            .unwrap_or_default();
+    }
+
+    fn get_protector_role(&self) -> Role {
+        if self.info.config.protection.bodyguard {
+            Role::Bodyguard
+        } else {
+            Role::Doctor
+        }
     }
 
-    fn get_streamer(&mut self, timeout: Duration) -> InternalStreamer {
+    /// Person id of the alive doctor ( or bodyguard ), if any.
+    async fn get_protector_id(&self) -> Option<String> {
+        get_alive_persons_with_role(
+            self.get_client(),
+            self.get_village_id(),
+            self.get_protector_role(),
+        )
+        .await
+        .first()
+        .map(|p| p.get_id())
+    }
+
+    fn get_streamer(&mut self, timeout: Duration) -> InternalStreamer<'_> {
         InternalStreamer::new(self, timeout)
     }
 
-    async fn apply_and_report_night_action(&self, choices: NightEventsStorage) {
-        use NightActionResult::*;
+    /// Marks the person dead and announces it, revealing as much as the village allows.
+    ///
+    /// The dead don't die twice.
+    async fn kill_person(&mut self, person_id: &str, cause: DeathCause) {
+        let alive = get_all_alive_persons(self.get_client(), self.get_village_id()).await;
+        if !alive.iter().any(|p| p.get_id() == person_id) {
+            return;
+        }
 
-        // Apply actions ...
-        let (wolves_vitim, saved, seen) = choices.wolves_doctor_seer_choices();
-        match wolves_vitim {
-            Some(wolves_vitim) => match saved {
-                Some(saved) if saved == wolves_vitim => {
-                    // Saved
-                    self.notify_night_action_result(PersonSaved(saved))
+        let role = get_person_role(self.get_client(), person_id).await;
+        mark_dead(self.get_client(), person_id).await.unwrap();
+        self.dying.push(person_id.to_string());
+
+        self.notify(FromVillage::PersonDied {
+            person_id: person_id.to_string(),
+            cause,
+            revealed: self.info.config.death_reveal.reveal(role),
+        })
+        .await
+        .unwrap_or_default();
+    }
+
+    /// Whether the person died but his last words are yet to come.
+    ///
+    /// Until then he can't see the graveyard, so nothing of it leaks to the living.
+    fn awaits_last_words(&self, person_id: &str) -> bool {
+        self.dying.iter().any(|dying| dying == person_id)
+            || matches!(&self.last_words, Some((dying, _)) if dying == person_id)
+    }
+
+    /// Welcome to the graveyard, here's everything.
+    async fn welcome_to_graveyard(&self, person_id: &str) {
+        let graveyard = self.info.config.graveyard;
+        if graveyard.enabled && graveyard.spectate {
+            self.notify(FromVillage::Spectate {
+                recipients: vec![person_id.to_string()],
+                event: SpectatorEvent::Roles(
+                    get_village_persons(self.get_client(), self.get_village_id()).await,
+                ),
+            })
+            .await
+            .unwrap_or_default();
+        }
+    }
+
+    /// An alive person left the running game, he dies without last words.
+    async fn forfeit(&mut self, person_id: &str) {
+        let alive = get_all_alive_persons(self.get_client(), self.get_village_id()).await;
+        if !alive.iter().any(|p| p.get_id() == person_id) {
+            self.notify(FromVillage::LeaveVillage(LeaveResult::Failed(
+                "Only alive persons can forfeit.".to_string(),
+            )))
+            .await
+            .unwrap_or_default();
+            return;
+        }
+
+        self.notify(FromVillage::LeaveVillage(LeaveResult::Forfeited(
+            person_id.to_string(),
+        )))
+        .await
+        .unwrap_or_default();
+        self.kill_person(person_id, DeathCause::Forfeit).await;
+        self.dying.retain(|dying| dying != person_id);
+        self.welcome_to_graveyard(person_id).await;
+        self.check_game_status(&GameEvent::HeadCount).await;
+    }
+
+    /// Persons who were `expected` to act but didn't, lose their patience points.
+    ///
+    /// The forest takes whoever missed too many actions in a row.
+    async fn track_afk(&mut self, expected: Vec<String>, acted: &[String]) {
+        let max_missed = match self.info.config.afk {
+            Some(rules) => rules.max_missed,
+            None => return,
+        };
+
+        let alive = get_all_alive_persons(self.get_client(), self.get_village_id()).await;
+        let mut taken = false;
+        for person_id in expected {
+            if !alive.iter().any(|p| p.get_id() == person_id) {
+                continue;
+            }
+
+            if acted.contains(&person_id) {
+                self.afk.acted(&person_id);
+                continue;
+            }
+
+            match self.afk.missed(&person_id, max_missed) {
+                AfkStatus::Active => (),
+                AfkStatus::Warned(missed) => {
+                    self.notify(FromVillage::AfkWarning { person_id, missed })
                         .await
                         .unwrap_or_default();
                 }
-                _ => {
-                    // Ok, victim is not saved ( or doctor failed to choose ).
-                    // The victim is dead :(
-                    mark_dead(self.get_client(), &wolves_vitim).await.unwrap();
+                AfkStatus::Gone => {
+                    self.kill_person(&person_id, DeathCause::Forest).await;
+                    // He wasn't talking anyway.
+                    self.dying.retain(|dying| *dying != person_id);
+                    self.welcome_to_graveyard(&person_id).await;
+                    taken = true;
+                }
+            }
+        }
 
-                    self.notify_night_action_result(PersonEaten(wolves_vitim))
-                        .await
-                        .unwrap_or_default();
+        if taken {
+            self.check_game_status(&GameEvent::HeadCount).await;
+        }
+    }
+
+    /// Tracks persons who had a night turn tonight.
+    async fn track_night_afk(&mut self, protector_id: Option<String>) {
+        let alive = get_all_alive_persons(self.get_client(), self.get_village_id()).await;
+        let (wolves, doctor, seer) = self.night_events.wolves_doctor_seer_choices();
+        let serial_killer = self.night_events.serial_killer_choice();
+
+        let mut expected = vec![];
+        let mut acted = vec![];
+        for person in alive.iter() {
+            let chose = match person.get_role() {
+                Role::Wolf | Role::MasterWolf => wolves.is_some(),
+                Role::Seer => seer.is_some(),
+                Role::SerialKiller => serial_killer.is_some(),
+                _ if protector_id == Some(person.get_id()) => doctor.is_some(),
+                _ => continue,
+            };
+
+            expected.push(person.get_id());
+            if chose {
+                acted.push(person.get_id());
+            }
+        }
+
+        self.track_afk(expected, &acted).await;
+    }
+
+    /// Shares a live event with dead persons, if they're allowed to spectate.
+    async fn spectate(&self, event: SpectatorEvent) {
+        let graveyard = self.info.config.graveyard;
+        if !graveyard.enabled || !graveyard.spectate {
+            return;
+        }
+
+        let recipients: Vec<String> = get_village_persons(self.get_client(), self.get_village_id())
+            .await
+            .into_iter()
+            .filter(|p| !p.is_alive() && !self.awaits_last_words(&p.get_id()))
+            .map(|p| p.get_id())
+            .collect();
+        if !recipients.is_empty() {
+            self.notify(FromVillage::Spectate { recipients, event })
+                .await
+                .unwrap_or_default();
+        }
+    }
+
+    /// Applies a night kill, unless the victim is protected.
+    ///
+    /// `guarded` is set once the bodyguard died, he can't die twice a night.
+    /// Returns `false` if the victim is neither dead nor saved, a cursed one turned into a wolf.
+    async fn apply_night_kill(
+        &mut self,
+        victim: String,
+        by_wolves: bool,
+        saved: Option<&str>,
+        protector_id: Option<&str>,
+        guarded: &mut bool,
+    ) -> bool {
+        use NightActionResult::*;
+
+        if saved == Some(victim.as_str()) {
+            if !self.info.config.protection.bodyguard {
+                // Saved
+                self.notify_night_action_result(PersonSaved(victim))
+                    .await
+                    .unwrap_or_default();
+                return true;
+            }
+
+            if let Some(bodyguard) = protector_id.filter(|&guard| guard != victim) {
+                // The bodyguard dies in place of the target.
+                if !*guarded {
+                    *guarded = true;
+                    // Guarding gives his role away, unless roles are revealed anyway.
+                    let reveal_role = matches!(self.info.config.death_reveal, DeathReveal::Role);
+                    let cause = match (reveal_role, by_wolves) {
+                        (true, _) => DeathCause::Guarding,
+                        (false, true) => DeathCause::Eaten,
+                        (false, false) => DeathCause::Killed,
+                    };
+                    self.kill_person(bodyguard, cause).await;
+
+                    self.notify_night_action_result(PersonGuarded {
+                        saved: victim,
+                        bodyguard: reveal_role.then(|| bodyguard.to_string()),
+                    })
+                    .await
+                    .unwrap_or_default();
                 }
-            },
-            None => {
+                return true;
+            }
+        }
+
+        if by_wolves
+            && matches!(
+                get_person_role(self.get_client(), &victim).await,
+                Role::Cursed
+            )
+        {
+            // The cursed one joins the wolves instead, no one knows.
+            change_person_role(self.get_client(), &victim, Role::Wolf)
+                .await
+                .unwrap();
+
+            self.notify(FromVillage::PersonConverted {
+                person_id: victim,
+                role: Role::Wolf,
+            })
+            .await
+            .unwrap_or_default();
+            self.notify_night_action_result(NoneEaten)
+                .await
+                .unwrap_or_default();
+            return false;
+        }
+
+        // Ok, victim is not saved ( or doctor failed to choose ).
+        // The victim is dead :(
+        let cause = match by_wolves {
+            true => DeathCause::Eaten,
+            false => DeathCause::Killed,
+        };
+        self.kill_person(&victim, cause).await;
+
+        self.notify_night_action_result(match by_wolves {
+            true => PersonEaten(victim),
+            false => PersonKilled(victim),
+        })
+        .await
+        .unwrap_or_default();
+        true
+    }
+
+    async fn apply_and_report_night_action(&mut self, protector_id: Option<String>) {
+        use NightActionResult::*;
+
+        // Apply actions ...
+        let (wolves_vitim, saved, seen) = self.night_events.wolves_doctor_seer_choices();
+        let serial_killer_victim = self.night_events.serial_killer_choice();
+        let mut guarded = false;
+        // Whether the wolves' victim is dead or saved by now.
+        let mut wolves_vitim_settled = false;
+
+        match wolves_vitim.clone() {
+            // Wolves can't eat each other, or the serial killer.
+            Some(wolves_vitim)
+                if get_person_role(self.get_client(), &wolves_vitim)
+                    .await
+                    .is_eatable() =>
+            {
+                wolves_vitim_settled = self
+                    .apply_night_kill(
+                        wolves_vitim,
+                        true,
+                        saved.as_deref(),
+                        protector_id.as_deref(),
+                        &mut guarded,
+                    )
+                    .await;
+            }
+            _ => {
                 // None eaten
                 // No need to check for saved ...
                 self.notify_night_action_result(NoneEaten)
@@ -213,6 +562,24 @@ impl VillageMain {
             }
         }
 
+        match serial_killer_victim {
+            // Already handled with the wolves' attack.
+            Some(victim) if wolves_vitim_settled && Some(&victim) == wolves_vitim.as_ref() => (),
+            Some(victim) => {
+                self.apply_night_kill(
+                    victim,
+                    false,
+                    saved.as_deref(),
+                    protector_id.as_deref(),
+                    &mut guarded,
+                )
+                .await;
+            }
+            None => {
+                // Serial killer failed to choose someone ...
+            }
+        }
+
         match seen {
             Some(seen) => {
                 // Sent report to seer
@@ -228,45 +595,636 @@ impl VillageMain {
         }
     }
 
-    async fn preform_night_actions(&mut self, timeout: Duration) {
+    /// Night choices, then their results.
+    ///
+    /// Returns `true` if the village is dead meanwhile.
+    async fn preform_night_actions(&mut self, timeout: Duration) -> bool {
+        self.night_events.begin_night();
+        let protection = self.info.config.protection;
+        let protector = self.get_protector_role();
+        let protector_id = self.get_protector_id().await;
+        let has_serial_killer = !get_alive_persons_with_role(
+            self.get_client(),
+            self.get_village_id(),
+            Role::SerialKiller,
+        )
+        .await
+        .is_empty();
+
         let mut streamer = self.get_streamer(timeout);
-        let mut choices = NightEventsStorage::new();
 
         // Ask for roles to execute night action ...
         // 1. Wolves may decide to eat.
         streamer.vg().notify(FromVillage::WolvesTurn).await.unwrap();
         if let Ok(person_id) = streamer.wait_for_wolves_choice().await {
-            choices.set_wolves_choice(&person_id)
+            let vg = streamer.vg();
+            vg.night_events.set_wolves_choice(&person_id);
+            vg.spectate(SpectatorEvent::NightChoice(NightChoice::Wolves(person_id)))
+                .await;
         }
         // Village dead ☠️
         else if streamer.village_dead() {
-            return;
+            return true;
         }
 
-        // 2. Doctor may save.
-        streamer.vg().notify(FromVillage::DoctorTurn).await.unwrap();
+        // 2. Serial killer may kill, if there's any.
+        if has_serial_killer {
+            streamer
+                .vg()
+                .notify(FromVillage::SerialKillerTurn)
+                .await
+                .unwrap();
+            streamer.reset(timeout);
+            if let Ok(person_id) = streamer.wait_for_serial_killer_choice().await {
+                let vg = streamer.vg();
+                vg.night_events.set_serial_killer_choice(&person_id);
+                vg.spectate(SpectatorEvent::NightChoice(NightChoice::SerialKiller(
+                    person_id,
+                )))
+                .await;
+            }
+            // Village dead ☠️
+            else if streamer.village_dead() {
+                return true;
+            }
+        }
+
+        // 3. Doctor may save.
+        let excluded = streamer
+            .vg()
+            .night_events
+            .doctor_excluded(protector_id.as_deref(), &protection);
+        streamer
+            .vg()
+            .notify(FromVillage::DoctorTurn {
+                protector,
+                excluded,
+            })
+            .await
+            .unwrap();
         streamer.reset(timeout);
-        if let Ok(person_id) = streamer.wait_for_doctor_choice().await {
-            choices.set_doctor_choice(&person_id)
+        while let Ok(person_id) = streamer.wait_for_doctor_choice().await {
+            let vg = streamer.vg();
+            match vg.night_events.check_doctor_choice(
+                &person_id,
+                protector_id.as_deref(),
+                &protection,
+            ) {
+                Ok(_) => {
+                    vg.night_events
+                        .set_doctor_choice(&person_id, protector_id.as_deref());
+                    vg.spectate(SpectatorEvent::NightChoice(NightChoice::Doctor(person_id)))
+                        .await;
+                    break;
+                }
+                Err(reason) => {
+                    // Wait for a better choice.
+                    vg.notify(FromVillage::NightChoiceRejected(reason))
+                        .await
+                        .unwrap();
+                }
+            }
         }
         // Village dead ☠️
-        else if streamer.village_dead() {
-            return;
+        if streamer.village_dead() {
+            return true;
         }
 
-        // 3. Detective or Seer may scan roles
+        // 4. Detective or Seer may scan roles
         streamer.vg().notify(FromVillage::SeerTurn).await.unwrap();
         streamer.reset(timeout);
         if let Ok(person_id) = streamer.wait_for_seer_choice().await {
-            choices.set_seer_choice(&person_id)
+            let vg = streamer.vg();
+            vg.night_events.set_seer_choice(&person_id);
+            vg.spectate(SpectatorEvent::NightChoice(NightChoice::Seer(person_id)))
+                .await;
         }
         // Village dead ☠️
         else if streamer.village_dead() {
-            return;
+            return true;
         }
 
         // Apply actions ...
-        self.apply_and_report_night_action(choices).await;
+        self.apply_and_report_night_action(protector_id.clone())
+            .await;
+        self.track_night_afk(protector_id).await;
+
+        false
+    }
+
+    /// Day discussion, persons may reveal their roles meanwhile.
+    ///
+    /// Returns `true` if the village is dead meanwhile.
+    async fn preform_sun_raise(&mut self, timeout: Duration) -> bool {
+        self.trial = Trial::new();
+
+        let mut streamer = self.get_streamer(timeout);
+        while let Ok(data) = streamer.next().await {
+            match data {
+                SafeVillageInternal::RevealRequested(person_id) => {
+                    streamer.vg().reveal_person(&person_id).await;
+                }
+                SafeVillageInternal::Nominated { nominator, nominee } => {
+                    streamer.vg().nominate(&nominator, &nominee).await;
+                }
+                _ => continue,
+            }
+        }
+
+        streamer.village_dead()
+    }
+
+    /// Nominates a suspect for the trial, if trials are enabled.
+    async fn nominate(&mut self, nominator: &str, nominee: &str) {
+        let alive = get_all_alive_persons(self.get_client(), self.get_village_id()).await;
+        let is_alive = |id: &str| alive.iter().any(|p| p.get_id() == id);
+
+        let nominated = if self.info.config.trial.is_none() {
+            Err("There are no trials in this village.".to_string())
+        } else if !is_alive(nominator) || !is_alive(nominee) {
+            Err("Nominator and nominee should be alive.".to_string())
+        } else {
+            self.trial.nominate(nominator, nominee)
+        };
+
+        match nominated {
+            Ok(_) => {
+                self.notify(FromVillage::Nominated {
+                    nominator: nominator.to_string(),
+                    nominee: nominee.to_string(),
+                })
+                .await
+                .unwrap_or_default();
+            }
+            Err(reason) => {
+                self.notify(FromVillage::TrialActionRejected(reason))
+                    .await
+                    .unwrap_or_default();
+            }
+        }
+    }
+
+    /// How much a lynch vote counts, revealed roles may count more.
+    fn vote_weight(&self, alive: &[Person], voter: &str) -> u32 {
+        match alive.iter().find(|p| p.get_id() == voter) {
+            Some(voter) if self.revealed_persons.contains(&voter.get_id()) => {
+                voter.get_role().lynch_vote_weight()
+            }
+            _ => 1,
+        }
+    }
+
+    /// Defense of the accused, then the verdict. No one is lynched without an accused.
+    ///
+    /// Returns `true` if the village is dead meanwhile.
+    async fn preform_trial(&mut self, rules: TrialRules) -> bool {
+        let accused = match self.trial.begin_defense() {
+            Some(accused) => accused,
+            None => {
+                self.notify(FromVillage::ReportLynchResult(LynchResult::NoneLynched))
+                    .await
+                    .unwrap_or_default();
+                self.check_game_status(&GameEvent::HeadCount).await;
+                return false;
+            }
+        };
+
+        // 1. The accused defends himself, only he can talk now.
+        self.notify(FromVillage::OnTrial {
+            accused: accused.clone(),
+            dur: rules.defense_dur,
+        })
+        .await
+        .unwrap();
+        let mut streamer = self.get_streamer(rules.defense_dur);
+        while let Ok(data) = streamer.next().await {
+            if let SafeVillageInternal::RevealRequested(person_id) = data {
+                streamer.vg().reveal_person(&person_id).await;
+            }
+        }
+        // Village dead ☠️
+        if streamer.village_dead() {
+            return true;
+        }
+
+        // 2. Guilty or innocent?
+        self.trial.begin_verdict();
+        self.notify(FromVillage::VerdictTime {
+            accused: accused.clone(),
+            dur: rules.verdict_dur,
+        })
+        .await
+        .unwrap();
+        let alive = get_all_alive_persons(self.get_client(), self.get_village_id()).await;
+        let mut streamer = self.get_streamer(rules.verdict_dur);
+        while let Ok(data) = streamer.next().await {
+            match data {
+                SafeVillageInternal::VerdictVoted { voter, guilty } => {
+                    let vg = streamer.vg();
+                    let voted = match alive.iter().any(|p| p.get_id() == voter) {
+                        true => vg.trial.vote(&voter, guilty),
+                        false => Err("Only alive persons can vote.".to_string()),
+                    };
+                    match voted {
+                        Ok(_) => vg.notify(FromVillage::VerdictVoted { voter, guilty }),
+                        Err(reason) => vg.notify(FromVillage::TrialActionRejected(reason)),
+                    }
+                    .await
+                    .unwrap_or_default();
+                }
+                SafeVillageInternal::RevealRequested(person_id) => {
+                    streamer.vg().reveal_person(&person_id).await;
+                }
+                _ => continue,
+            }
+        }
+        // Village dead ☠️
+        if streamer.village_dead() {
+            return true;
+        }
+
+        let expected = alive
+            .iter()
+            .map(|p| p.get_id())
+            .filter(|id| *id != accused)
+            .collect();
+
+        // Verdict first, a guilty accused is already dead when the idle voters are tracked.
+        if self
+            .trial
+            .is_guilty(|voter| self.vote_weight(&alive, voter))
+        {
+            self.lynch_person(accused).await;
+        } else {
+            self.notify(FromVillage::ReportLynchResult(LynchResult::Acquitted(
+                accused,
+            )))
+            .await
+            .unwrap_or_default();
+            self.check_game_status(&GameEvent::HeadCount).await;
+        }
+
+        self.track_afk(expected, &self.trial.voters()).await;
+
+        false
+    }
+
+    /// Gives each person died since the last call a chance to say one last public message.
+    ///
+    /// Returns `true` if the village is dead meanwhile.
+    async fn preform_last_words(&mut self, dur: Duration) -> bool {
+        while !self.dying.is_empty() {
+            let person_id = self.dying.remove(0);
+            self.last_words = Some((person_id.clone(), false));
+            self.notify(FromVillage::LastWords {
+                person_id: person_id.clone(),
+                dur,
+            })
+            .await
+            .unwrap();
+
+            let village_dead = self.get_streamer(dur).timeout_or_die().await;
+            self.last_words = None;
+            if village_dead {
+                return true;
+            }
+            self.welcome_to_graveyard(&person_id).await;
+        }
+
+        false
+    }
+
+    fn is_night(&self) -> bool {
+        matches!(self.current_period_raw, RawPeriod::FirstNight)
+            || (matches!(self.current_period_raw, RawPeriod::DaytimeCycle)
+                && matches!(self.current_daytime, Some(Daytime::MidNight)))
+    }
+
+    /// During a defense only the accused can talk.
+    fn is_silenced(&self, person_id: &str) -> bool {
+        match self.trial.stage() {
+            TrialStage::Defense(accused) => accused != person_id,
+            _ => false,
+        }
+    }
+
+    fn is_day(&self) -> bool {
+        matches!(self.current_period_raw, RawPeriod::DaytimeCycle)
+            && matches!(
+                self.current_daytime,
+                Some(Daytime::SunRaise | Daytime::LynchTime | Daytime::Dusk)
+            )
+    }
+
+    /// Relays a chat message from a person to the channel he can talk in right now.
+    async fn post_message(&mut self, sender_id: &str, text: &str) {
+        let persons = get_village_persons(self.get_client(), self.get_village_id()).await;
+        let (alive, dead): (Vec<_>, Vec<_>) = persons.into_iter().partition(|p| p.is_alive());
+        let message = |channel| ChatMessage {
+            channel,
+            sender_id: sender_id.to_string(),
+            text: text.to_string(),
+        };
+
+        let rejected = match alive.iter().find(|p| p.get_id() == sender_id) {
+            // Only the dying person talks, once.
+            _ if self.last_words.is_some() => match &mut self.last_words {
+                Some((dying, said)) if dying == sender_id && !*said => {
+                    *said = true;
+                    self.notify(FromVillage::ChatMessage(message(ChatChannel::Village)))
+                        .await
+                        .unwrap_or_default();
+                    None
+                }
+                Some((dying, _)) if dying == sender_id => Some("You already said your last words."),
+                _ => Some("Let the dying speak."),
+            },
+            None if self.awaits_last_words(sender_id) => Some("Wait for your last words."),
+            None if dead.iter().any(|p| p.get_id() == sender_id)
+                && self.info.config.graveyard.enabled =>
+            {
+                // Only dead persons can read it, once their last words are said.
+                self.notify(FromVillage::PrivateChatMessage {
+                    recipients: dead
+                        .iter()
+                        .map(|p| p.get_id())
+                        .filter(|id| !self.awaits_last_words(id))
+                        .collect(),
+                    message: message(ChatChannel::Graveyard),
+                })
+                .await
+                .unwrap_or_default();
+                None
+            }
+            None => Some("Dead persons can't talk."),
+            Some(sender) if self.is_day() && self.is_silenced(&sender.get_id()) => {
+                Some("Only the accused can talk now.")
+            }
+            Some(_) if self.is_day() => {
+                self.notify(FromVillage::ChatMessage(message(ChatChannel::Village)))
+                    .await
+                    .unwrap_or_default();
+                None
+            }
+            Some(sender) if self.is_night() && sender.get_role().team() == Team::Wolves => {
+                // Only alive wolves can read it.
+                let recipients = alive
+                    .iter()
+                    .filter(|p| p.get_role().team() == Team::Wolves)
+                    .map(|p| p.get_id())
+                    .collect();
+                self.notify(FromVillage::PrivateChatMessage {
+                    recipients,
+                    message: message(ChatChannel::Wolves),
+                })
+                .await
+                .unwrap_or_default();
+                None
+            }
+            Some(_) if self.is_night() => Some("Only wolves can talk at night."),
+            Some(_) => Some("You can't talk right now."),
+        };
+
+        if let Some(reason) = rejected {
+            self.notify(FromVillage::MessageRejected {
+                sender_id: sender_id.to_string(),
+                reason: reason.to_string(),
+            })
+            .await
+            .unwrap_or_default();
+        }
+    }
+
+    /// Publicly reveals role of an alive person, if his role allows it.
+    async fn reveal_person(&mut self, person_id: &str) {
+        let alive = get_all_alive_persons(self.get_client(), self.get_village_id()).await;
+        let rejected = match alive.iter().find(|p| p.get_id() == person_id) {
+            None => Some("Only alive persons can reveal themselves."),
+            Some(person) if !person.get_role().can_reveal() => Some("Your role can't be revealed."),
+            Some(_) if self.revealed_persons.contains(person_id) => {
+                Some("You're already revealed.")
+            }
+            Some(person) => {
+                self.revealed_persons.insert(person_id.to_string());
+                self.notify(FromVillage::PersonRevealed {
+                    person_id: person_id.to_string(),
+                    role: person.get_role(),
+                })
+                .await
+                .unwrap_or_default();
+                None
+            }
+        };
+
+        if let Some(reason) = rejected {
+            self.notify(FromVillage::RevealRejected(reason.to_string()))
+                .await
+                .unwrap_or_default();
+        }
+    }
+
+    /// Collects lynch votes until the timeout, then lynches the most voted person.
+    ///
+    /// Returns `true` if the village is dead meanwhile.
+    async fn preform_lynch(&mut self, timeout: Duration) -> bool {
+        let alive = get_all_alive_persons(self.get_client(), self.get_village_id()).await;
+        let is_alive = |id: &str| alive.iter().any(|p| p.get_id() == id);
+        let mut votes = LynchVotes::new();
+
+        self.notify(FromVillage::LynchTurn).await.unwrap();
+
+        let mut streamer = self.get_streamer(timeout);
+        while let Ok(data) = streamer.next().await {
+            match data {
+                SafeVillageInternal::LynchVoted { voter, target } => {
+                    if !is_alive(&voter) || !is_alive(&target) {
+                        streamer
+                            .vg()
+                            .notify(FromVillage::LynchVoteRejected(
+                                "Voter and target should be alive.".to_string(),
+                            ))
+                            .await
+                            .unwrap();
+                        continue;
+                    }
+
+                    votes.vote(&voter, &target);
+                    streamer
+                        .vg()
+                        .notify(FromVillage::LynchVoted { voter, target })
+                        .await
+                        .unwrap();
+                }
+                SafeVillageInternal::RevealRequested(person_id) => {
+                    streamer.vg().reveal_person(&person_id).await;
+                }
+                _ => continue,
+            }
+        }
+
+        // Village dead ☠️
+        if streamer.village_dead() {
+            return true;
+        }
+
+        // The lynch comes first, whoever dies by it isn't taken by the forest too.
+        match votes.lynched(|voter| self.vote_weight(&alive, voter)) {
+            Some(lynched) => self.lynch_person(lynched).await,
+            None => {
+                self.notify(FromVillage::ReportLynchResult(LynchResult::NoneLynched))
+                    .await
+                    .unwrap_or_default();
+                self.check_game_status(&GameEvent::HeadCount).await;
+            }
+        }
+
+        self.track_afk(alive.iter().map(|p| p.get_id()).collect(), &votes.voters())
+            .await;
+
+        false
+    }
+
+    /// Lynches the person, unless he left the village before the votes were counted.
+    async fn lynch_person(&mut self, person_id: String) {
+        let alive = get_all_alive_persons(self.get_client(), self.get_village_id()).await;
+        let lynched = match alive.iter().find(|p| p.get_id() == person_id) {
+            Some(lynched) => lynched,
+            None => {
+                self.notify(FromVillage::ReportLynchResult(LynchResult::NoneLynched))
+                    .await
+                    .unwrap_or_default();
+                self.check_game_status(&GameEvent::HeadCount).await;
+                return;
+            }
+        };
+
+        self.kill_person(&person_id, DeathCause::Lynched).await;
+        self.notify(FromVillage::ReportLynchResult(LynchResult::PersonLynched(
+            person_id,
+        )))
+        .await
+        .unwrap_or_default();
+        self.check_game_status(&GameEvent::Lynched(lynched)).await;
+    }
+
+    /// Checks win conditions, returns `true` if the game is over.
+    ///
+    /// Once the game is over, the victory doesn't change anymore.
+    async fn check_game_status(&mut self, event: &GameEvent<'_>) -> bool {
+        if self.victory.is_none() {
+            let alive = get_all_alive_persons(self.get_client(), self.get_village_id()).await;
+            self.victory = evaluate(&alive, event);
+        }
+        self.victory.is_some()
+    }
+
+    async fn report_game_end(&self) -> Result<(), mpsc::error::SendError<WorldInlet>> {
+        let (winner, neutral_winners) = match &self.victory {
+            Some(victory) => (victory.winner, victory.neutral_winners.clone()),
+            None => (None, vec![]),
+        };
+
+        self.notify(FromVillage::GameEnded(GameReport {
+            winner,
+            neutral_winners,
+            persons: get_village_persons(self.get_client(), self.get_village_id()).await,
+        }))
+        .await
+    }
+
+    /// Asks persons to play again, returns `true` if a new round is ready.
+    ///
+    /// Persons who don't accept in time are dropped. Returns `None` if the village died meanwhile.
+    async fn offer_rematch(&mut self, rules: RematchRules) -> Option<bool> {
+        let roster: HashSet<String> = get_village_persons(self.get_client(), self.get_village_id())
+            .await
+            .iter()
+            .map(|person| person.get_id())
+            .collect();
+        self.notify(FromVillage::RematchOffered {
+            dur: rules.answer_dur,
+        })
+        .await
+        .unwrap_or_default();
+
+        let mut answers = HashMap::new();
+        let mut streamer = self.get_streamer(rules.answer_dur);
+        while let Ok(data) = streamer.next().await {
+            if let SafeVillageInternal::RematchAnswered { person, accept } = data {
+                let vg = streamer.vg();
+                if !roster.contains(&person) {
+                    vg.notify(FromVillage::RematchRejected(
+                        "You're not in this village.".to_string(),
+                    ))
+                    .await
+                    .unwrap_or_default();
+                    continue;
+                }
+
+                answers.insert(person.clone(), accept);
+                vg.notify(FromVillage::RematchAnswered {
+                    person_id: person,
+                    accept,
+                })
+                .await
+                .unwrap_or_default();
+
+                // Everyone answered, no need to wait.
+                if answers.len() == roster.len() {
+                    break;
+                }
+            }
+        }
+        if let ExitFlag::VillageDead = streamer.get_exit_err() {
+            return None;
+        }
+
+        let accepted: Vec<String> = roster
+            .into_iter()
+            .filter(|person_id| answers.get(person_id).copied().unwrap_or(false))
+            .collect();
+        let min_persons = match self.info.resolve_period(&RawPeriod::Populating) {
+            Period::Populating { min_persons, .. } => min_persons as usize,
+            _ => 0,
+        };
+        if accepted.len() < min_persons {
+            self.notify(FromVillage::RematchCancelled(format!(
+                "{} persons accepted, at least {min_persons} are needed.",
+                accepted.len()
+            )))
+            .await
+            .unwrap_or_default();
+            return Some(false);
+        }
+
+        let client = self.get_client();
+        let village_id = self.get_village_id();
+        for person in get_village_persons(client, village_id).await {
+            if !accepted.contains(&person.get_id()) {
+                remove_person(client, village_id, &person.get_id())
+                    .await
+                    .unwrap_or_default();
+            }
+        }
+        reset_persons(client, village_id).await.unwrap();
+        self.reset_round();
+
+        self.notify(FromVillage::RematchStarted { persons: accepted })
+            .await
+            .unwrap_or_default();
+        Some(true)
+    }
+
+    /// Forgets everything about the last round.
+    fn reset_round(&mut self) {
+        self.night_events = NightEventsStorage::new();
+        self.victory = None;
+        self.revealed_persons.clear();
+        self.current_daytime = None;
+        self.trial = Trial::new();
+        self.dying.clear();
+        self.last_words = None;
+        self.afk = AfkTracker::new();
     }
 
     pub(super) async fn run(&mut self) {
@@ -278,104 +1236,237 @@ impl VillageMain {
             self.get_village_id()
         );
 
-        loop {
-            self.cross_period().await.unwrap();
-            self.notify_period_cross().await.unwrap();
-
-            let current_period = self.get_current_period();
-            match current_period {
-                Period::None => break,
-                Period::Populating {
-                    min_persons,
-                    max_persons: _,
-                    max_dur,
-                } => {
-                    // Callback should only call if players are filled!
-                    let mut streamer = self.get_streamer(max_dur);
-
-                    while let Ok(data) = streamer.next().await {
-                        match data {
-                            SafeVillageInternal::PersonsFilled => {
-                                let vg = streamer.vg();
-                                vg.notify(PeriodReady(vg.current_period_raw.cross().unwrap()))
+        let plan = self.info.config.plan.clone();
+        let mut periods = plan.periods.clone();
+        'rounds: loop {
+            for &raw_period in periods.iter() {
+                self.enter_period(raw_period).await;
+                self.notify_period_cross().await.unwrap();
+
+                let current_period = self.get_current_period();
+                match current_period {
+                    Period::None => continue,
+                    Period::Populating {
+                        min_persons,
+                        max_persons,
+                        max_dur,
+                    } => {
+                        // Callback should only call if players are filled!
+                        let mut streamer = self.get_streamer(max_dur);
+                        let mut extended = Duration::ZERO;
+
+                        while let Ok(data) = streamer.next().await {
+                            match data {
+                                SafeVillageInternal::PersonsFilled => {
+                                    let vg = streamer.vg();
+                                    // Someone may have left meanwhile, the lobby is open again.
+                                    if vg.read_persons_count().await < max_persons.into() {
+                                        continue;
+                                    }
+
+                                    vg.notify(PeriodReady(vg.next_period_raw())).await.unwrap();
+                                    break;
+                                }
+                                SafeVillageInternal::PersonJoined {
+                                    person_id,
+                                    current_count,
+                                } => {
+                                    if let Some(rules) =
+                                        streamer.vg().info.config.population_extension
+                                    {
+                                        let extension = rules.next(extended);
+                                        extended += extension;
+                                        streamer.increase_timeout(extension);
+                                    }
+
+                                    let time_left = streamer.remaining();
+                                    streamer
+                                        .vg()
+                                        .notify(AddPerson(AddPersonResult::Added {
+                                            person_id,
+                                            current_count,
+                                            time_left,
+                                        }))
+                                        .await
+                                        .unwrap_or_default();
+                                    continue;
+                                }
+                                SafeVillageInternal::StartNow => {
+                                    let vg = streamer.vg();
+                                    let current_joined = vg.read_persons_count().await;
+                                    if current_joined < min_persons.into() {
+                                        vg.notify(StartRejected(format!(
+                                        "{current_joined} persons joined, at least {min_persons} are needed."
+                                    )))
                                     .await
-                                    .unwrap();
-                                break;
-                            }
-                            SafeVillageInternal::ExtendPopulationTime(time) => {
-                                streamer.increase_timeout(time);
-                                continue;
-                            }
-                            _ => continue,
-                        };
-                    }
+                                    .unwrap_or_default();
+                                        continue;
+                                    }
+
+                                    vg.notify(PeriodReady(vg.next_period_raw())).await.unwrap();
+                                    break;
+                                }
+                                SafeVillageInternal::AssignmentConfigured(mode) => {
+                                    streamer.vg().configure_assignment(mode, max_persons).await;
+                                    continue;
+                                }
+                                _ => continue,
+                            };
+                        }
 
-                    match streamer.get_exit_err() {
-                        ExitFlag::NotExited => (),
-                        ExitFlag::TimedOut => {
-                            let current_joined = self.read_persons_count().await;
+                        match streamer.get_exit_err() {
+                            ExitFlag::NotExited => (),
+                            ExitFlag::TimedOut | ExitFlag::Skipped => {
+                                let current_joined = self.read_persons_count().await;
 
-                            if current_joined < min_persons.into() {
-                                self.notify(PopulatingTimedOut).await.unwrap();
+                                if current_joined < min_persons.into() {
+                                    self.notify(PopulatingTimedOut).await.unwrap();
 
-                                // ❌ Village may gone ...
-                                self.cleanup_steps().await;
+                                    // ❌ Village may gone ...
+                                    self.cleanup_steps().await;
+                                    return;
+                                } else {
+                                    self.notify(PeriodReady(self.next_period_raw()))
+                                        .await
+                                        .unwrap();
+                                }
+                            }
+                            ExitFlag::VillageDead => {
                                 return;
-                            } else {
-                                self.notify(PeriodReady(self.current_period_raw.cross().unwrap()))
-                                    .await
-                                    .unwrap();
-                                break;
                             }
-                        }
-                        ExitFlag::VillageDead => {
-                            return;
-                        }
-                    };
-                }
-                Period::Assignments(_) => {
-                    let roles = self.assign_roles().await;
-                    self.notify(RawString(format!("Roles assigned: {:#?}.", roles)))
-                        .await
-                        .unwrap();
-                }
-                Period::FirstNight(dur) => {
-                    self.notify(RawString("Wolves may know each other now ...".to_string()))
-                        .await
-                        .unwrap();
+                        };
+                    }
+                    Period::Assignments(mode) => {
+                        // Kept for the rematches too.
+                        let configured = self.assignment.clone();
+                        let mode = configured.clone().unwrap_or(mode);
+                        let roles = self.assign_roles(&mode).await;
+                        self.notify(RawString(format!("Roles assigned: {:#?}.", roles)))
+                            .await
+                            .unwrap();
 
-                    if self.get_streamer(dur).timeout_or_die().await {
-                        return;
+                        // The host planned for a full village, report the roles actually played.
+                        if configured.is_some() {
+                            let balance = balance::evaluate(&roles, roles.len());
+                            self.notify(AssignmentConfigured { mode, balance })
+                                .await
+                                .unwrap_or_default();
+                        }
                     }
-                }
-                Period::DaytimeCycle(get_len) => {
-                    let mut current_daytime = Daytime::MidNight;
-                    loop {
-                        current_daytime = current_daytime.cross();
-                        let timeout = get_len(current_daytime);
-                        self.notify(DaytimeCycled(current_daytime, timeout))
+                    Period::FirstNight(dur) => {
+                        self.notify(RawString("Wolves may know each other now ...".to_string()))
                             .await
                             .unwrap();
 
-                        match current_daytime {
-                            Daytime::MidNight => self.preform_night_actions(timeout).await,
-                            Daytime::SunRaise => {
-                                // Check game status ...
-                                if self.get_streamer(timeout).timeout_or_die().await {
+                        if self.get_streamer(dur).timeout_or_die().await {
+                            return;
+                        }
+                    }
+                    Period::DaytimeCycle {
+                        daytime_dur: get_len,
+                        last_words_dur,
+                    } => {
+                        let mut cycles = 0;
+                        // Nothing to cycle, move on.
+                        while !plan.daytimes.is_empty() {
+                            for &current_daytime in plan.daytimes.iter() {
+                                self.current_daytime = Some(current_daytime);
+                                let timeout = get_len(current_daytime);
+                                // Trials announce their own stages, the lynch time isn't used.
+                                let is_trial = matches!(current_daytime, Daytime::LynchTime)
+                                    && self.info.config.trial.is_some();
+                                if !is_trial {
+                                    self.notify(DaytimeCycled(current_daytime, timeout))
+                                        .await
+                                        .unwrap();
+                                }
+
+                                let village_dead = match current_daytime {
+                                    Daytime::MidNight => {
+                                        if self.preform_night_actions(timeout).await
+                                            || self.preform_last_words(last_words_dur).await
+                                        {
+                                            return;
+                                        }
+
+                                        self.check_game_status(&GameEvent::HeadCount).await;
+                                        false
+                                    }
+                                    Daytime::SunRaise => self.preform_sun_raise(timeout).await,
+                                    Daytime::LynchTime => {
+                                        // Request alive players to vote ...
+                                        let village_dead = match self.info.config.trial {
+                                            Some(rules) => self.preform_trial(rules).await,
+                                            None => self.preform_lynch(timeout).await,
+                                        };
+                                        village_dead
+                                            || self.preform_last_words(last_words_dur).await
+                                    }
+                                    Daytime::Dusk => {
+                                        self.get_streamer(timeout).timeout_or_die().await
+                                    }
+                                };
+                                if village_dead {
                                     return;
                                 }
+
+                                if self.victory.is_some() {
+                                    break;
+                                }
                             }
-                            Daytime::LynchTime => {
-                                // Request alive players to vote ...
-                                if self.get_streamer(timeout).timeout_or_die().await {
-                                    return;
+
+                            if self.victory.is_some() {
+                                break;
+                            }
+
+                            cycles += 1;
+                            if plan.max_cycles.is_some_and(|max| cycles >= max) {
+                                // Out of cycles, decide the winner now.
+                                let alive =
+                                    get_all_alive_persons(self.get_client(), self.get_village_id())
+                                        .await;
+                                self.victory = Some(evaluate_final(&alive));
+                                break;
+                            }
+                        }
+                    }
+                    Period::Ending => {
+                        self.report_game_end().await.unwrap();
+
+                        if let Some(rules) = self.info.config.rematch {
+                            match self.offer_rematch(rules).await {
+                                Some(true) => {
+                                    // Same roster, no need to populate again.
+                                    periods = plan
+                                        .periods
+                                        .iter()
+                                        .filter(|raw| !matches!(raw, RawPeriod::Populating))
+                                        .copied()
+                                        .collect();
+                                    continue 'rounds;
                                 }
+                                Some(false) => {
+                                    self.notify(VillageDisposed).await.unwrap_or_default();
+                                }
+                                None => return,
                             }
                         }
+
+                        // ❌ Game is over, village is gone ...
+                        self.cleanup_steps().await;
+                        return;
                     }
                 }
-                Period::Ending => todo!(),
             }
+            break;
+        }
+
+        // The plan has no ending, end it anyway.
+        self.report_game_end().await.unwrap_or_default();
+        // No rematch is offered, the world won't dispose it on its own.
+        if self.info.config.rematch.is_some() {
+            self.notify(VillageDisposed).await.unwrap_or_default();
         }
+        self.cleanup_steps().await;
     }
 }
@@ -1,7 +1,14 @@
+use crate::world::village::config::ProtectionRules;
+
+/// Night choices of the current night, plus what's needed from previous nights.
 pub(super) struct NightEventsStorage {
     wolves_choice_person_id: Option<String>,
     doctor_choice_person_id: Option<String>,
     seer_choice_person_id: Option<String>,
+    serial_killer_choice_person_id: Option<String>,
+
+    last_doctor_choice_person_id: Option<String>,
+    self_protects: u8,
 }
 
 impl NightEventsStorage {
@@ -10,14 +17,28 @@ impl NightEventsStorage {
             wolves_choice_person_id: None,
             doctor_choice_person_id: None,
             seer_choice_person_id: None,
+            serial_killer_choice_person_id: None,
+            last_doctor_choice_person_id: None,
+            self_protects: 0,
         }
     }
 
+    /// Clears choices of the last night, remembering what the protector did.
+    pub(super) fn begin_night(&mut self) {
+        self.last_doctor_choice_person_id = self.doctor_choice_person_id.take();
+        self.wolves_choice_person_id = None;
+        self.seer_choice_person_id = None;
+        self.serial_killer_choice_person_id = None;
+    }
+
     pub(super) fn set_wolves_choice(&mut self, person_id: &str) {
         self.wolves_choice_person_id = Some(person_id.to_string());
     }
 
-    pub(super) fn set_doctor_choice(&mut self, person_id: &str) {
+    pub(super) fn set_doctor_choice(&mut self, person_id: &str, protector_id: Option<&str>) {
+        if protector_id == Some(person_id) {
+            self.self_protects += 1;
+        }
         self.doctor_choice_person_id = Some(person_id.to_string());
     }
 
@@ -25,6 +46,69 @@ impl NightEventsStorage {
         self.seer_choice_person_id = Some(person_id.to_string());
     }
 
+    pub(super) fn set_serial_killer_choice(&mut self, person_id: &str) {
+        self.serial_killer_choice_person_id = Some(person_id.to_string());
+    }
+
+    /// Checks if the protector is allowed to protect this person tonight.
+    ///
+    /// ## Errors
+    ///
+    /// Returns the reason if the choice breaks the [`ProtectionRules`].
+    pub(super) fn check_doctor_choice(
+        &self,
+        person_id: &str,
+        protector_id: Option<&str>,
+        rules: &ProtectionRules,
+    ) -> Result<(), String> {
+        if !rules.allow_repeat_target
+            && self.last_doctor_choice_person_id.as_deref() == Some(person_id)
+        {
+            return Err("Can't protect the same person two nights in a row.".to_string());
+        }
+
+        // He'd die in his own place, guarding himself saves no one.
+        if rules.bodyguard && protector_id == Some(person_id) {
+            return Err("A bodyguard can't guard himself.".to_string());
+        }
+
+        if protector_id == Some(person_id) && self.self_protects >= rules.max_self_protects {
+            return Err(format!(
+                "Can't protect yourself more than {} time(s).",
+                rules.max_self_protects
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Persons the protector is not allowed to choose tonight.
+    pub(super) fn doctor_excluded(
+        &self,
+        protector_id: Option<&str>,
+        rules: &ProtectionRules,
+    ) -> Vec<String> {
+        let mut excluded = vec![];
+        if !rules.allow_repeat_target {
+            if let Some(last) = &self.last_doctor_choice_person_id {
+                excluded.push(last.clone());
+            }
+        }
+
+        if let Some(protector_id) = protector_id {
+            if (rules.bodyguard || self.self_protects >= rules.max_self_protects)
+                && !excluded.iter().any(|e| e == protector_id)
+            {
+                excluded.push(protector_id.to_string());
+            }
+        }
+        excluded
+    }
+
+    pub(super) fn serial_killer_choice(&self) -> Option<String> {
+        self.serial_killer_choice_person_id.clone()
+    }
+
     pub(super) fn wolves_doctor_seer_choices(
         &self,
     ) -> (Option<String>, Option<String>, Option<String>) {
@@ -35,3 +119,73 @@ impl NightEventsStorage {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RULES: ProtectionRules = ProtectionRules {
+        allow_repeat_target: false,
+        max_self_protects: 1,
+        bodyguard: false,
+    };
+
+    #[test]
+    fn no_repeat_target() {
+        let mut storage = NightEventsStorage::new();
+        storage.begin_night();
+        storage.set_doctor_choice("a", Some("doc"));
+
+        storage.begin_night();
+        assert!(storage
+            .check_doctor_choice("a", Some("doc"), &RULES)
+            .is_err());
+        assert!(storage
+            .check_doctor_choice("b", Some("doc"), &RULES)
+            .is_ok());
+        assert_eq!(storage.doctor_excluded(Some("doc"), &RULES), vec!["a"]);
+
+        // Skipping a night forgets the last choice.
+        storage.begin_night();
+        assert!(storage
+            .check_doctor_choice("a", Some("doc"), &RULES)
+            .is_ok());
+    }
+
+    #[test]
+    fn self_protect_limit() {
+        let mut storage = NightEventsStorage::new();
+        storage.begin_night();
+        assert!(storage
+            .check_doctor_choice("doc", Some("doc"), &RULES)
+            .is_ok());
+        storage.set_doctor_choice("doc", Some("doc"));
+
+        storage.begin_night();
+        storage.begin_night();
+        assert!(storage
+            .check_doctor_choice("doc", Some("doc"), &RULES)
+            .is_err());
+        assert_eq!(storage.doctor_excluded(Some("doc"), &RULES), vec!["doc"]);
+    }
+
+    #[test]
+    fn bodyguard_never_guards_himself() {
+        let rules = ProtectionRules {
+            bodyguard: true,
+            ..RULES
+        };
+        let mut storage = NightEventsStorage::new();
+        storage.begin_night();
+        assert!(storage
+            .check_doctor_choice("guard", Some("guard"), &rules)
+            .is_err());
+        assert!(storage
+            .check_doctor_choice("a", Some("guard"), &rules)
+            .is_ok());
+        assert_eq!(
+            storage.doctor_excluded(Some("guard"), &rules),
+            vec!["guard"]
+        );
+    }
+}
@@ -0,0 +1,154 @@
+use std::collections::{HashMap, HashSet};
+
+/// Stages of a day with trials, inside the daytime cycle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum TrialStage {
+    /// Persons nominate suspects, until one of them is seconded.
+    Nominating,
+    /// Only the accused can talk.
+    Defense(String),
+    /// Village votes guilty or innocent.
+    Verdict(String),
+}
+
+pub(super) struct Trial {
+    stage: TrialStage,
+    /// Nominee to persons who nominated him.
+    nominations: HashMap<String, HashSet<String>>,
+    accused: Option<String>,
+    /// Voter to his verdict, `true` for guilty.
+    verdicts: HashMap<String, bool>,
+}
+
+impl Trial {
+    pub(super) fn new() -> Self {
+        Self {
+            stage: TrialStage::Nominating,
+            nominations: HashMap::new(),
+            accused: None,
+            verdicts: HashMap::new(),
+        }
+    }
+
+    pub(super) fn stage(&self) -> &TrialStage {
+        &self.stage
+    }
+
+    /// Nominates a suspect, returns `true` if this nomination seconded him.
+    ///
+    /// ## Errors
+    ///
+    /// Returns the reason if nominations are closed or not allowed.
+    pub(super) fn nominate(&mut self, nominator: &str, nominee: &str) -> Result<bool, String> {
+        if self.stage != TrialStage::Nominating {
+            return Err("Nominations are closed.".to_string());
+        }
+
+        if self.accused.is_some() {
+            return Err("Someone is already going on trial.".to_string());
+        }
+
+        if nominator == nominee {
+            return Err("You can't nominate yourself.".to_string());
+        }
+
+        let nominators = self.nominations.entry(nominee.to_string()).or_default();
+        if !nominators.insert(nominator.to_string()) {
+            return Err("You already nominated this person.".to_string());
+        }
+
+        if nominators.len() >= 2 {
+            self.accused = Some(nominee.to_string());
+            return Ok(true);
+        }
+
+        Ok(false)
+    }
+
+    /// Moves to defense stage, if there's someone to accuse.
+    pub(super) fn begin_defense(&mut self) -> Option<String> {
+        let accused = self.accused.clone()?;
+        self.stage = TrialStage::Defense(accused.clone());
+        Some(accused)
+    }
+
+    pub(super) fn begin_verdict(&mut self) {
+        if let TrialStage::Defense(accused) = &self.stage {
+            self.stage = TrialStage::Verdict(accused.clone());
+        }
+    }
+
+    /// Registers or changes the verdict of `voter`.
+    ///
+    /// ## Errors
+    ///
+    /// Returns the reason if it's not verdict time, or the accused is voting.
+    pub(super) fn vote(&mut self, voter: &str, guilty: bool) -> Result<(), String> {
+        match &self.stage {
+            TrialStage::Verdict(accused) if accused == voter => {
+                Err("The accused can't vote.".to_string())
+            }
+            TrialStage::Verdict(_) => {
+                self.verdicts.insert(voter.to_string(), guilty);
+                Ok(())
+            }
+            _ => Err("It's not verdict time.".to_string()),
+        }
+    }
+
+    pub(super) fn voters(&self) -> Vec<String> {
+        self.verdicts.keys().cloned().collect()
+    }
+
+    /// The accused is guilty if guilty votes outweigh innocent ones.
+    pub(super) fn is_guilty<W>(&self, weight: W) -> bool
+    where
+        W: Fn(&str) -> u32,
+    {
+        let (mut guilty, mut innocent) = (0, 0);
+        for (voter, verdict) in self.verdicts.iter() {
+            match verdict {
+                true => guilty += weight(voter),
+                false => innocent += weight(voter),
+            }
+        }
+
+        guilty > innocent
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seconded_nominee_goes_on_trial() {
+        let mut trial = Trial::new();
+        assert!(trial.nominate("a", "a").is_err());
+        assert_eq!(trial.nominate("a", "c"), Ok(false));
+        assert!(trial.nominate("a", "c").is_err());
+        assert_eq!(trial.nominate("b", "d"), Ok(false));
+        assert_eq!(trial.nominate("b", "c"), Ok(true));
+        assert!(trial.nominate("e", "d").is_err());
+
+        assert_eq!(trial.begin_defense(), Some("c".to_string()));
+        assert!(trial.vote("a", true).is_err());
+
+        trial.begin_verdict();
+        assert!(trial.vote("c", false).is_err());
+        trial.vote("a", true).unwrap();
+        trial.vote("b", false).unwrap();
+        assert!(!trial.is_guilty(|_| 1));
+
+        trial.vote("d", true).unwrap();
+        assert!(trial.is_guilty(|_| 1));
+    }
+
+    #[test]
+    fn no_trial_without_second() {
+        let mut trial = Trial::new();
+        trial.nominate("a", "b").unwrap();
+        assert_eq!(trial.begin_defense(), None);
+        assert_eq!(trial.stage(), &TrialStage::Nominating);
+    }
+}
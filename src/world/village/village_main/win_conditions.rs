@@ -0,0 +1,201 @@
+use crate::world::person::{
+    roles::{Role, Team},
+    Person,
+};
+
+/// What happened right before checking the game status.
+pub(super) enum GameEvent<'e> {
+    /// Someone may have died, only head-counts matter.
+    HeadCount,
+    /// The village lynched this person.
+    Lynched(&'e Person),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub(super) struct Victory {
+    pub(super) winner: Option<Team>,
+    pub(super) neutral_winners: Vec<String>,
+}
+
+/// Checks if the game is over, using alive persons and the last event.
+pub(super) fn evaluate(alive: &[Person], event: &GameEvent) -> Option<Victory> {
+    // Neutral roles win alone, the game ends right away.
+    if let GameEvent::Lynched(lynched) = event {
+        if matches!(lynched.get_role(), Role::Tanner) {
+            return Some(Victory {
+                winner: None,
+                neutral_winners: vec![lynched.get_id()],
+            });
+        }
+    }
+
+    // No one survived, no one won.
+    if alive.is_empty() {
+        return Some(Victory {
+            winner: None,
+            neutral_winners: vec![],
+        });
+    }
+
+    // No team wins while the serial killer is around, he wins if he's the last one.
+    let killers: Vec<String> = alive
+        .iter()
+        .filter(|p| matches!(p.get_role(), Role::SerialKiller))
+        .map(|p| p.get_id())
+        .collect();
+    if !killers.is_empty() {
+        if killers.len() < alive.len() {
+            return None;
+        }
+
+        return Some(Victory {
+            winner: None,
+            neutral_winners: killers,
+        });
+    }
+
+    let wolves = alive
+        .iter()
+        .filter(|p| p.get_role().team() == Team::Wolves)
+        .count();
+    let others = alive.len() - wolves;
+
+    let winner = if wolves == 0 {
+        Team::Village
+    } else if wolves >= others {
+        Team::Wolves
+    } else {
+        return None;
+    };
+
+    Some(Victory {
+        winner: Some(winner),
+        neutral_winners: vec![],
+    })
+}
+
+/// Decides the winner when the game runs out of days.
+///
+/// Same as [`evaluate`] if the game is over anyway. Otherwise wolves win if any of them survived,
+/// else the village. The serial killer still wins only as the last one standing.
+pub(super) fn evaluate_final(alive: &[Person]) -> Victory {
+    if let Some(victory) = evaluate(alive, &GameEvent::HeadCount) {
+        return victory;
+    }
+
+    let wolves_alive = alive.iter().any(|p| p.get_role().team() == Team::Wolves);
+    Victory {
+        winner: Some(match wolves_alive {
+            true => Team::Wolves,
+            false => Team::Village,
+        }),
+        neutral_winners: vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn person(id: &str, role: Role) -> Person {
+        Person::new(id.to_string(), "village", role.into(), role.is_eatable())
+    }
+
+    #[test]
+    fn head_count() {
+        let alive = vec![
+            person("w", Role::Wolf),
+            person("v1", Role::Villager),
+            person("v2", Role::Villager),
+        ];
+        assert_eq!(evaluate(&alive, &GameEvent::HeadCount), None);
+        assert_eq!(
+            evaluate(&alive[..2], &GameEvent::HeadCount),
+            Some(Victory {
+                winner: Some(Team::Wolves),
+                neutral_winners: vec![],
+            })
+        );
+        assert_eq!(
+            evaluate(&alive[1..], &GameEvent::HeadCount),
+            Some(Victory {
+                winner: Some(Team::Village),
+                neutral_winners: vec![],
+            })
+        );
+    }
+
+    #[test]
+    fn serial_killer_last_one_standing() {
+        let alive = [
+            person("k", Role::SerialKiller),
+            person("w", Role::Wolf),
+            person("v", Role::Villager),
+        ];
+        // Wolves are not winning while the serial killer is alive.
+        assert_eq!(evaluate(&alive[..2], &GameEvent::HeadCount), None);
+        assert_eq!(
+            evaluate(&alive[..1], &GameEvent::HeadCount),
+            Some(Victory {
+                winner: None,
+                neutral_winners: vec!["k".to_string()],
+            })
+        );
+        assert_eq!(
+            evaluate(&alive[1..], &GameEvent::HeadCount),
+            Some(Victory {
+                winner: Some(Team::Wolves),
+                neutral_winners: vec![],
+            })
+        );
+    }
+
+    #[test]
+    fn lynched_tanner_wins_alone() {
+        let tanner = person("t", Role::Tanner);
+        let alive = vec![
+            person("w", Role::Wolf),
+            person("v1", Role::Villager),
+            person("v2", Role::Villager),
+        ];
+        assert_eq!(
+            evaluate(&alive, &GameEvent::Lynched(&tanner)),
+            Some(Victory {
+                winner: None,
+                neutral_winners: vec!["t".to_string()],
+            })
+        );
+    }
+
+    #[test]
+    fn out_of_days() {
+        let alive = [
+            person("v", Role::Villager),
+            person("w", Role::Wolf),
+            person("v2", Role::Villager),
+        ];
+        assert_eq!(evaluate_final(&alive).winner, Some(Team::Wolves));
+        assert_eq!(evaluate_final(&alive[..1]).winner, Some(Team::Village));
+
+        // The serial killer doesn't share the victory.
+        let alive = [
+            person("k", Role::SerialKiller),
+            person("w", Role::Wolf),
+            person("v", Role::Villager),
+        ];
+        assert_eq!(
+            evaluate_final(&alive),
+            Victory {
+                winner: Some(Team::Wolves),
+                neutral_winners: vec![],
+            }
+        );
+        assert_eq!(
+            evaluate_final(&alive[..1]),
+            Victory {
+                winner: None,
+                neutral_winners: vec!["k".to_string()],
+            }
+        );
+    }
+}
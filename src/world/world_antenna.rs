@@ -1,15 +1,19 @@
 use crate::tower::Antenna;
 
+use super::world_outlet::VillageStatus;
+
 #[derive(Debug, Clone)]
 pub enum AskWorld {
     RawString(String),
     AskVillageName(String),
+    AskVillageStatus(String),
 }
 
 #[derive(Debug, Clone)]
 pub enum WorldAnswered {
     RawString(String),
     VillageName(Option<String>),
+    VillageStatus(Option<VillageStatus>),
 }
 
 #[derive(Debug, Clone)]
@@ -32,6 +36,16 @@ impl WorldAntenna {
         }
     }
 
+    pub async fn ask_village_status(&self, village_id: &str) -> Option<VillageStatus> {
+        match self
+            .ask(AskWorld::AskVillageStatus(village_id.to_string()))
+            .await?
+        {
+            WorldAnswered::VillageStatus(status) => status,
+            _ => None,
+        }
+    }
+
     pub async fn ask_raw_string(&self, raw: &str) -> Option<String> {
         match self.ask(AskWorld::RawString(raw.to_string())).await? {
             WorldAnswered::RawString(name) => Some(name),
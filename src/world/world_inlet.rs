@@ -1,13 +1,35 @@
 use std::time::Duration;
 
-use super::village::periods::{Daytime, Period, RawPeriod};
+use super::{
+    chat::ChatMessage,
+    person::balance::BalanceReport,
+    person::{
+        roles::{Role, RoleReveal, Team},
+        Person,
+    },
+    village::periods::{AssignmentMode, Daytime, Period, PeriodPlan, RawPeriod},
+};
 
 #[derive(Debug, Clone)]
 pub enum AddPersonResult {
     Added {
         person_id: String,
         current_count: u64,
+        /// The new deadline, as time left until populating ends.
+        time_left: Duration,
+    },
+    Failed(String),
+}
+
+#[derive(Debug, Clone)]
+pub enum LeaveResult {
+    /// Left before the game started.
+    Left {
+        person_id: String,
+        current_count: u64,
     },
+    /// Left after the game started, the person is dead.
+    Forfeited(String),
     Failed(String),
 }
 
@@ -15,10 +37,78 @@ pub enum AddPersonResult {
 pub enum NightActionResult {
     NoneEaten,
     PersonEaten(String),
+    /// Killed by the serial killer.
+    PersonKilled(String),
     PersonSaved(String),
+    /// The bodyguard died in place of the person he protected.
+    PersonGuarded {
+        saved: String,
+        /// Only named if dead persons' roles are revealed.
+        bodyguard: Option<String>,
+    },
     SeerReport(String, bool),
 }
 
+#[derive(Debug, Clone, Copy)]
+pub enum DeathCause {
+    /// Eaten by wolves.
+    Eaten,
+    /// Killed by the serial killer.
+    Killed,
+    /// Bodyguard died protecting someone, only if dead persons' roles are revealed.
+    Guarding,
+    Lynched,
+    /// Left the game.
+    Forfeit,
+    /// Idle for too long.
+    Forest,
+}
+
+#[derive(Debug, Clone)]
+pub enum LynchResult {
+    NoneLynched,
+    PersonLynched(String),
+    /// The accused is found innocent.
+    Acquitted(String),
+}
+
+/// Sent once the game is over.
+#[derive(Debug, Clone)]
+pub struct GameReport {
+    /// The winner team, `None` if a neutral role won alone.
+    pub winner: Option<Team>,
+    /// Id of neutral persons who won.
+    pub neutral_winners: Vec<String>,
+    pub persons: Vec<Person>,
+}
+
+#[derive(Debug, Clone)]
+pub enum NightChoice {
+    Wolves(String),
+    Doctor(String),
+    Seer(String),
+    SerialKiller(String),
+}
+
+impl NightChoice {
+    /// Id of the chosen person.
+    pub fn target(&self) -> &str {
+        match self {
+            NightChoice::Wolves(target)
+            | NightChoice::Doctor(target)
+            | NightChoice::Seer(target)
+            | NightChoice::SerialKiller(target) => target,
+        }
+    }
+}
+
+/// What dead persons see while spectating.
+#[derive(Debug, Clone)]
+pub enum SpectatorEvent {
+    Roles(Vec<Person>),
+    NightChoice(NightChoice),
+}
+
 #[derive(Debug, Clone)]
 pub enum FromVillage {
     RawString(String),
@@ -27,14 +117,137 @@ pub enum FromVillage {
     NewPeriod(Period),
 
     PopulatingTimedOut,
+    /// The current phase is frozen with `remaining` time left.
+    Paused {
+        remaining: Duration,
+    },
+    Resumed {
+        remaining: Duration,
+    },
+    PauseRejected(String),
+    PhaseSkipped,
+    SkipRejected(String),
+    StartRejected(String),
+    /// The host changed how roles are assigned, with balance of the roles.
+    AssignmentConfigured {
+        mode: AssignmentMode,
+        balance: BalanceReport,
+    },
+    AssignmentRejected(String),
     DaytimeCycled(Daytime, Duration),
+    /// The current phase ends in `remaining`.
+    TimeReminder {
+        remaining: Duration,
+    },
     AddPerson(AddPersonResult),
+    LeaveVillage(LeaveResult),
+    /// Removed by the host while populating.
+    PersonKicked {
+        person_id: String,
+        banned: bool,
+        current_count: u64,
+    },
+    KickRejected(String),
 
     WolvesTurn,
-    DoctorTurn,
+    DoctorTurn {
+        protector: Role,
+        excluded: Vec<String>,
+    },
     SeerTurn,
+    SerialKillerTurn,
+    NightChoiceRejected(String),
 
     ReportNightActionResult(NightActionResult),
+    PersonDied {
+        person_id: String,
+        cause: DeathCause,
+        revealed: RoleReveal,
+    },
+    PersonConverted {
+        person_id: String,
+        role: Role,
+    },
+
+    PersonRevealed {
+        person_id: String,
+        role: Role,
+    },
+    RevealRejected(String),
+
+    ChatMessage(ChatMessage),
+    /// Chat message only `recipients` can read.
+    PrivateChatMessage {
+        recipients: Vec<String>,
+        message: ChatMessage,
+    },
+    MessageRejected {
+        sender_id: String,
+        reason: String,
+    },
+    /// The person missed too many actions, the next one is his last.
+    AfkWarning {
+        person_id: String,
+        missed: u8,
+    },
+    /// Result of a person asking for a role, before the assignments.
+    RolePreferred {
+        person_id: String,
+        result: Result<Role, String>,
+    },
+    Spectate {
+        recipients: Vec<String>,
+        event: SpectatorEvent,
+    },
+
+    LynchTurn,
+    LynchVoted {
+        voter: String,
+        target: String,
+    },
+    LynchVoteRejected(String),
+
+    Nominated {
+        nominator: String,
+        nominee: String,
+    },
+    OnTrial {
+        accused: String,
+        dur: Duration,
+    },
+    /// A person just died, he can say one last thing.
+    LastWords {
+        person_id: String,
+        dur: Duration,
+    },
+    VerdictTime {
+        accused: String,
+        dur: Duration,
+    },
+    VerdictVoted {
+        voter: String,
+        guilty: bool,
+    },
+    TrialActionRejected(String),
+
+    ReportLynchResult(LynchResult),
+
+    GameEnded(GameReport),
+    /// Persons can accept a new round within `dur`.
+    RematchOffered {
+        dur: Duration,
+    },
+    RematchAnswered {
+        person_id: String,
+        accept: bool,
+    },
+    RematchRejected(String),
+    /// A new round begins with these persons.
+    RematchStarted {
+        persons: Vec<String>,
+    },
+    /// Not enough persons accepted, the village is disposed.
+    RematchCancelled(String),
 }
 
 #[derive(Debug, Clone)]
@@ -48,11 +261,95 @@ pub enum FromHeaven {
         village_id: String,
         count: u8,
     },
+    LeaveVillage {
+        village_id: String,
+        person_id: String,
+    },
     KillVillage {
         village_id: String,
     },
+    /// Removes a person while populating, `ban` to keep him from joining again.
+    KickPerson {
+        village_id: String,
+        person_id: String,
+        ban: bool,
+    },
+    PauseVillage {
+        village_id: String,
+    },
+    ResumeVillage {
+        village_id: String,
+    },
+    /// Ends the current phase right away.
+    SkipPhase {
+        village_id: String,
+    },
+    /// Starts the game before the village is full.
+    StartNow {
+        village_id: String,
+    },
+    NightChoice {
+        village_id: String,
+        choice: NightChoice,
+    },
+    Reveal {
+        village_id: String,
+        person_id: String,
+    },
+    SendMessage {
+        village_id: String,
+        sender_id: String,
+        text: String,
+    },
+    LynchVote {
+        village_id: String,
+        voter_id: String,
+        target_id: String,
+    },
+    Nominate {
+        village_id: String,
+        nominator_id: String,
+        nominee_id: String,
+    },
+    Verdict {
+        village_id: String,
+        voter_id: String,
+        guilty: bool,
+    },
+    PreferRole {
+        village_id: String,
+        person_id: String,
+        role: Role,
+    },
+    ConfigureAssignment {
+        village_id: String,
+        mode: AssignmentMode,
+    },
+    AnswerRematch {
+        village_id: String,
+        person_id: String,
+        accept: bool,
+    },
     ListVillages,
     NewVillage,
+    /// A village playing one night and one lynch only.
+    NewQuickVillage,
+    /// A village playing a plan of the host's own.
+    NewPlannedVillage(PeriodPlan),
+}
+
+/// Who is sending a command from heaven.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Caller(pub String);
+
+impl Caller {
+    pub fn new(id: &str) -> Self {
+        Caller(id.to_string())
+    }
+
+    pub fn id(&self) -> &str {
+        &self.0
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -61,7 +358,10 @@ pub enum WorldInlet {
         village_id: String,
         data: FromVillage,
     },
-    FromHeaven(FromHeaven),
+    FromHeaven {
+        caller: Caller,
+        data: FromHeaven,
+    },
 }
 
 impl WorldInlet {
@@ -71,4 +371,8 @@ impl WorldInlet {
             data,
         }
     }
+
+    pub fn from_heaven(caller: Caller, data: FromHeaven) -> WorldInlet {
+        WorldInlet::FromHeaven { caller, data }
+    }
 }
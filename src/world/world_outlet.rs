@@ -3,9 +3,17 @@ use std::time::Duration;
 use tokio::sync::mpsc::{error::SendError, Sender};
 
 use super::{
-    person::Person,
-    village::periods::{Daytime, Period, RawPeriod},
-    world_inlet::{AddPersonResult, NightActionResult},
+    chat::ChatMessage,
+    person::{
+        balance::BalanceReport,
+        roles::{Role, RoleReveal},
+        Person,
+    },
+    village::periods::{AssignmentMode, Daytime, Period, RawPeriod},
+    world_inlet::{
+        AddPersonResult, Caller, DeathCause, GameReport, LeaveResult, LynchResult,
+        NightActionResult, SpectatorEvent,
+    },
 };
 
 #[derive(Debug)]
@@ -31,11 +39,29 @@ impl VillageLiteInfo {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct PersonStatus {
+    pub person_id: String,
+    pub is_alive: bool,
+    /// What's known about the person, `None` while alive.
+    pub revealed: Option<RoleReveal>,
+}
+
+#[derive(Debug, Clone)]
+pub struct VillageStatus {
+    pub village_id: String,
+    pub village_name: String,
+    pub period: RawPeriod,
+    pub persons: Vec<PersonStatus>,
+}
+
 #[derive(Debug)]
 pub enum NightTurn {
     Wolf,
     Doctor,
+    Bodyguard,
     Seer,
+    SerialKiller,
 }
 
 #[derive(Debug)]
@@ -46,23 +72,131 @@ pub enum WithVillage {
     PeriodReady(RawPeriod),
     NewPeriod(Period),
     PopulationTimedOut,
+    AssignmentConfigured {
+        mode: AssignmentMode,
+        balance: BalanceReport,
+    },
+    AssignmentRejected(String),
     DaytimeCycled(Daytime, Duration),
+    TimeReminder {
+        remaining: Duration,
+    },
+    Paused {
+        remaining: Duration,
+    },
+    Resumed {
+        remaining: Duration,
+    },
+    PauseRejected(String),
+    PhaseSkipped,
+    SkipRejected(String),
+    StartRejected(String),
     AddPersonResult(AddPersonResult),
+    LeaveResult(LeaveResult),
+    PersonKicked {
+        person_id: String,
+        banned: bool,
+        current_count: u64,
+    },
+    KickRejected(String),
     NightActionResultReport(NightActionResult),
+    NightChoiceRejected(String),
+    PersonDied {
+        person_id: String,
+        cause: DeathCause,
+        revealed: RoleReveal,
+    },
     NightTurn {
         turn: NightTurn,
         available_persons: Vec<Person>,
     },
+    PersonRevealed {
+        person_id: String,
+        role: Role,
+    },
+    RevealRejected(String),
+    ChatMessage(ChatMessage),
+    LynchTurn {
+        available_persons: Vec<Person>,
+    },
+    LynchVoted {
+        voter: String,
+        target: String,
+    },
+    LynchVoteRejected(String),
+    Nominated {
+        nominator: String,
+        nominee: String,
+    },
+    OnTrial {
+        accused: String,
+        dur: Duration,
+    },
+    LastWords {
+        person_id: String,
+        dur: Duration,
+    },
+    VerdictTime {
+        accused: String,
+        dur: Duration,
+    },
+    VerdictVoted {
+        voter: String,
+        guilty: bool,
+    },
+    TrialActionRejected(String),
+    LynchResultReport(LynchResult),
+    GameEnded(GameReport),
+    RematchOffered {
+        dur: Duration,
+    },
+    RematchAnswered {
+        person_id: String,
+        accept: bool,
+    },
+    RematchRejected(String),
+    RematchStarted {
+        persons: Vec<String>,
+    },
+    RematchCancelled(String),
+}
+
+/// Private data, only the person should see it.
+#[derive(Debug)]
+pub enum ToPerson {
+    RoleChanged(Role),
+    ChatMessage(ChatMessage),
+    MessageRejected(String),
+    Spectate(SpectatorEvent),
+    /// Missed actions in a row, one more and you're gone.
+    AfkWarning(u8),
+    /// The preferred role, or why it's rejected.
+    RolePreference(Result<Role, String>),
+    /// Removed from the village by the host.
+    Kicked {
+        banned: bool,
+    },
 }
 
 #[derive(Debug)]
 pub enum WorldOutlet {
     RawStringResult(Result<String, String>),
     VillageList(Vec<VillageLiteInfo>),
+    /// Caller is neither the village host nor an admin.
+    PermissionDenied {
+        caller: Caller,
+        village_id: String,
+        action: String,
+    },
     WithVillage {
         village_id: String,
         data: WithVillage,
     },
+    ToPerson {
+        village_id: String,
+        person_id: String,
+        data: ToPerson,
+    },
 }
 
 impl<'tx> WorldOutlet {
@@ -133,6 +267,23 @@ impl<'tx> SendWorldOutletWithVillageContext<'tx> {
         .await
     }
 
+    pub async fn to_person(
+        self,
+        person_id: &str,
+        data: ToPerson,
+    ) -> Result<(), SendError<WorldOutlet>> {
+        ReadyToSend {
+            outlet: WorldOutlet::ToPerson {
+                village_id: self.village_id,
+                person_id: person_id.to_string(),
+                data,
+            },
+            tx: self.tx,
+        }
+        .send()
+        .await
+    }
+
     pub async fn raw_string(self, raw: String) -> Result<(), SendError<WorldOutlet>> {
         self.send(WithVillage::RawString(raw)).await
     }